@@ -67,3 +67,212 @@ pub fn derive_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
     proc_macro::TokenStream::from(expanded)
 }
+
+#[proc_macro_derive(Value, attributes(xql))]
+pub fn derive_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    let expanded = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }),
+            ..
+        }) if unnamed.len() == 1 => Ok(derive_newtype_value(&input, &unnamed[0].ty)),
+        syn::Data::Enum(data) => derive_enum_value(&input, data),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Value can only be derived for newtype structs and fieldless enums",
+        )),
+    };
+
+    proc_macro::TokenStream::from(expanded.unwrap_or_else(syn::Error::into_compile_error))
+}
+
+fn derive_newtype_value(input: &syn::DeriveInput, inner: &syn::Type) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|clause| &clause.predicates);
+
+    let params = &input.generics.params;
+    let impl_generics = quote::quote!(<'__xql, #params>);
+
+    // `&inner: Into<Value>` recurses into this very impl when inner is a type
+    // parameter, so generic newtypes convert a copy of the inner value.
+    let by_ref = if input.generics.params.is_empty() {
+        quote::quote! {
+            impl<'__xql> ::std::convert::From<&'__xql #name> for ::xql::value::Value<'__xql>
+            where
+                &'__xql #inner: ::std::convert::Into<::xql::value::Value<'__xql>>,
+            {
+                #[inline]
+                fn from(val: &'__xql #name) -> Self {
+                    (&val.0).into()
+                }
+            }
+        }
+    } else {
+        quote::quote! {
+            impl #impl_generics ::std::convert::From<&'__xql #name #ty_generics> for ::xql::value::Value<'__xql>
+            where
+                #inner: ::std::marker::Copy + ::std::convert::Into<::xql::value::Value<'__xql>>,
+                #predicates
+            {
+                #[inline]
+                fn from(val: &'__xql #name #ty_generics) -> Self {
+                    val.0.into()
+                }
+            }
+        }
+    };
+
+    quote::quote! {
+        impl #impl_generics ::std::convert::From<#name #ty_generics> for ::xql::value::Value<'__xql>
+        where
+            #inner: ::std::convert::Into<::xql::value::Value<'__xql>>,
+            #predicates
+        {
+            #[inline]
+            fn from(val: #name #ty_generics) -> Self {
+                val.0.into()
+            }
+        }
+
+        #by_ref
+    }
+}
+
+fn derive_enum_value(
+    input: &syn::DeriveInput,
+    data: &syn::DataEnum,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let name = &input.ident;
+    let mut rename_all = None;
+    let mut repr = None;
+    for (key, val) in xql_attrs(&input.attrs)? {
+        if key.is_ident("rename_all") {
+            rename_all = Some(val);
+        } else if key.is_ident("repr") {
+            repr = Some(val);
+        } else {
+            return Err(syn::Error::new_spanned(key, "unknown xql attribute"));
+        }
+    }
+
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "Value can only be derived for fieldless enums",
+        ));
+    }
+
+    let arms = match repr {
+        Some(repr) => {
+            if let Some(rename_all) = rename_all {
+                return Err(syn::Error::new_spanned(
+                    rename_all,
+                    "rename_all can not be used together with repr",
+                ));
+            }
+            for variant in data.variants.iter() {
+                if let Some((key, _)) = xql_attrs(&variant.attrs)?.into_iter().next() {
+                    return Err(syn::Error::new_spanned(
+                        key,
+                        "xql attributes on variants can not be used together with repr",
+                    ));
+                }
+            }
+            let ty = match repr.value().as_str() {
+                "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" => {
+                    syn::Ident::new(repr.value().as_str(), repr.span())
+                }
+                _ => return Err(syn::Error::new_spanned(repr, "unsupported repr")),
+            };
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    quote::quote! {
+                        #name::#ident => ::xql::value::Value::from(#name::#ident as #ty)
+                    }
+                })
+                .collect::<Vec<_>>()
+        }
+        None => {
+            let mut arms = Vec::new();
+            for variant in data.variants.iter() {
+                let ident = &variant.ident;
+                let mut label = match &rename_all {
+                    Some(rename_all) => rename(&ident.to_string(), rename_all)?,
+                    None => ident.to_string(),
+                };
+                for (key, val) in xql_attrs(&variant.attrs)? {
+                    if !key.is_ident("rename") {
+                        return Err(syn::Error::new_spanned(key, "unknown xql attribute"));
+                    }
+                    label = val.value();
+                }
+                arms.push(quote::quote! {
+                    #name::#ident => ::xql::value::Value::Text(#label)
+                });
+            }
+            arms
+        }
+    };
+
+    Ok(quote::quote! {
+        impl<'a> ::std::convert::From<&#name> for ::xql::value::Value<'a> {
+            #[inline]
+            fn from(val: &#name) -> Self {
+                match val {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        impl<'a> ::std::convert::From<#name> for ::xql::value::Value<'a> {
+            #[inline]
+            fn from(val: #name) -> Self {
+                (&val).into()
+            }
+        }
+
+    })
+}
+
+/// Collect `key = "value"` pairs out of `#[xql(...)]` attributes.
+fn xql_attrs(attrs: &[syn::Attribute]) -> Result<Vec<(syn::Path, syn::LitStr)>, syn::Error> {
+    let mut pairs = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("xql")) {
+        let list = match attr.parse_meta()? {
+            syn::Meta::List(list) => list,
+            meta => return Err(syn::Error::new_spanned(meta, "expected #[xql(...)]")),
+        };
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                })) => pairs.push((path, lit)),
+                nested => return Err(syn::Error::new_spanned(nested, "expected key = \"value\"")),
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+fn rename(name: &str, rule: &syn::LitStr) -> Result<String, syn::Error> {
+    use convert_case::{Case, Casing};
+
+    let renamed = match rule.value().as_str() {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "PascalCase" => name.to_case(Case::Pascal),
+        "camelCase" => name.to_case(Case::Camel),
+        "snake_case" => name.to_case(Case::Snake),
+        "SCREAMING_SNAKE_CASE" => name.to_case(Case::ScreamingSnake),
+        "kebab-case" => name.to_case(Case::Kebab),
+        "SCREAMING-KEBAB-CASE" => name.to_case(Case::Cobol),
+        _ => return Err(syn::Error::new_spanned(rule, "unsupported rename_all rule")),
+    };
+    Ok(renamed)
+}
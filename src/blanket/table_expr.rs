@@ -1,4 +1,4 @@
-use crate::table_expr::TableExpr;
+use crate::item::Table;
 
 macro_rules! gen_method {
     ($join:ident cond) => {
@@ -17,13 +17,36 @@ macro_rules! gen_method {
         #[inline]
         fn $join<R, E>(self, right: R, cond: E) -> $crate::table_expr::TableExpr<'a>
         where
-            R: Into<$crate::table_expr::TableExpr<'a>>,
+            R: Into<$crate::item::Table<'a>>,
             E: Into<$crate::expr::Expr<'a>>,
         {
             $crate::ops::$join(self, right, cond)
         }
     };
 
+    ($join:ident using) => {
+        #[doc = concat!("A short hand for [`xql::ops::", stringify!($join), "`]", "(crate::ops::", stringify!($join), ").")]
+        #[doc = ""]
+        #[doc = "```"]
+        #[doc = concat!("use xql::ops::", stringify!($join), ";")]
+        #[doc = concat!("use xql::blanket::TableExprExt;")]
+        #[doc = ""]
+        #[doc = "assert_eq!("]
+        #[doc = concat!(r#"    "book"."#, stringify!($join), r#"("review", ["book_id"]),"#)]
+        #[doc = concat!(r#"    "#, stringify!($join), r#"("book", "review", ["book_id"]),"#)]
+        #[doc = ");"]
+        #[doc = "```"]
+        #[inline]
+        fn $join<R, I, C>(self, right: R, columns: I) -> $crate::table_expr::TableExpr<'a>
+        where
+            R: Into<$crate::item::Table<'a>>,
+            C: Into<$crate::item::Ident<'a>>,
+            I: IntoIterator<Item = C>,
+        {
+            $crate::ops::$join(self, right, columns)
+        }
+    };
+
     ($join:ident) => {
         #[doc = concat!("A short hand for [`xql::ops::", stringify!($join), "`]", "(crate::ops::", stringify!($join), ").")]
         #[doc = ""]
@@ -40,7 +63,7 @@ macro_rules! gen_method {
         #[inline]
         fn $join<R>(self, right: R) -> $crate::table_expr::TableExpr<'a>
         where
-            R: Into<$crate::table_expr::TableExpr<'a>>,
+            R: Into<$crate::item::Table<'a>>,
         {
             $crate::ops::$join(self, right)
         }
@@ -48,12 +71,16 @@ macro_rules! gen_method {
 }
 
 /// Extends anything that can be converted to
-/// [`TableExpr`](crate::table_expr::TableExpr) with it's related functions.
-pub trait TableExprExt<'a>: Into<crate::table_expr::TableExpr<'a>> {
+/// [`Table`](crate::item::Table) with it's related functions.
+pub trait TableExprExt<'a>: Into<Table<'a>> {
     gen_method!(join cond);
     gen_method!(left_join cond);
     gen_method!(right_join cond);
     gen_method!(full_join cond);
+    gen_method!(join_using using);
+    gen_method!(left_join_using using);
+    gen_method!(right_join_using using);
+    gen_method!(full_join_using using);
     gen_method!(cross_join);
     gen_method!(natural_join);
     gen_method!(natural_left_join);
@@ -61,7 +88,7 @@ pub trait TableExprExt<'a>: Into<crate::table_expr::TableExpr<'a>> {
     gen_method!(natural_full_join);
 }
 
-impl<'a, T> TableExprExt<'a> for T where T: Into<TableExpr<'a>> {}
+impl<'a, T> TableExprExt<'a> for T where T: Into<Table<'a>> {}
 
 #[test]
 #[cfg(test)]
@@ -121,4 +148,12 @@ fn test() {
         "category".natural_full_join("book"),
         natural_full_join("category", "book")
     );
+    assert_eq!(
+        "book".join_using("review", ["book_id"]),
+        join_using("book", "review", ["book_id"])
+    );
+    assert_eq!(
+        "book".left_join_using("review", ["book_id"]),
+        left_join_using("book", "review", ["book_id"])
+    );
 }
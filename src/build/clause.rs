@@ -1,127 +1,474 @@
+use super::unsupported;
 use super::Dialect;
+use super::Syntax;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::clause::Select<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("SELECT ");
         join!(D, sql, args, ", ", self.0);
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::Distinct<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str("DISTINCT");
+        if !self.0.is_empty() {
+            sql.push_str(" ON (");
+            join!(D, sql, args, ", ", self.0);
+            sql.push(')');
+        }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::From<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("FROM ");
         join!(D, sql, args, ", ", self.0);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Where<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("WHERE ");
-        self.0.build::<D>(sql, args);
+        self.0.build::<D>(sql, args)?;
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::GroupBy<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("GROUP BY ");
+        // a lone `ROLLUP` is what mysql spells `WITH ROLLUP`, anything else
+        // is emulated before reaching here.
+        if let (Syntax::MySql, [crate::expr::Expr::Rollup(_)]) = (D::SYNTAX, self.0.as_slice()) {
+            if let Some(crate::expr::Expr::Rollup(exprs)) = self.0.into_iter().next() {
+                join!(D, sql, args, ", ", exprs);
+            }
+            sql.push_str(" WITH ROLLUP");
+            return Ok(());
+        }
         join!(D, sql, args, ", ", self.0);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Having<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("HAVING ");
-        self.0.build::<D>(sql, args);
+        self.0.build::<D>(sql, args)?;
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::MergeWhen<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str(if self.matched {
+            "WHEN MATCHED"
+        } else {
+            "WHEN NOT MATCHED"
+        });
+        if let Some(cond) = self.cond {
+            sql.push_str(" AND ");
+            cond.build::<D>(sql, args)?;
+        }
+        sql.push_str(" THEN ");
+        match self.action {
+            crate::clause::MergeAction::Update(set) => {
+                sql.push_str("UPDATE ");
+                set.build::<D>(sql, args)?;
+            }
+            crate::clause::MergeAction::Delete => sql.push_str("DELETE"),
+            crate::clause::MergeAction::Insert(columns, row) => {
+                if !columns.is_empty() && columns.len() != row.0.len() {
+                    return Err(crate::error::Error::Invalid {
+                        reason: "MERGE INSERT with mismatched columns and values",
+                    });
+                }
+                sql.push_str("INSERT");
+                if !columns.is_empty() {
+                    sql.push_str(" (");
+                    join!(D, sql, args, ", ", columns);
+                    sql.push(')');
+                }
+                if row.0.is_empty() {
+                    sql.push_str(" DEFAULT VALUES");
+                } else {
+                    sql.push_str(" VALUES ");
+                    row.build::<D>(sql, args)?;
+                }
+            }
+            crate::clause::MergeAction::Nothing => sql.push_str("DO NOTHING"),
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::Window<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str("WINDOW ");
+        for (idx, (name, spec)) in self.0.into_iter().enumerate() {
+            if idx > 0 {
+                sql.push_str(", ");
+            }
+            name.build::<D>(sql, args)?;
+            sql.push_str(" AS (");
+            spec.build::<D>(sql, args)?;
+            sql.push(')');
+        }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::OrderBy<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("ORDER BY ");
         join!(D, sql, args, ", ", self.0);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Insert<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        sql.push_str("INSERT INTO ");
-        self.0.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        self.build_with::<D>("INSERT INTO", sql, args)
+    }
+}
+
+impl<'a> crate::clause::Insert<'a> {
+    pub(super) fn build_with<D: Dialect>(
+        self,
+        verb: &str,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str(verb);
+        sql.push(' ');
+        self.0.build::<D>(sql, args)?;
         if !self.1.is_empty() {
             sql.push('(');
             join!(D, sql, args, ", ", self.1);
             sql.push(')');
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Values<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("VALUES ");
         join!(D, sql, args, ", ", self.0);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Returning<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("RETURNING ");
         join!(D, sql, args, ", ", self.0);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Update<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("UPDATE ");
-        self.0.build::<D>(sql, args);
+        self.0.build::<D>(sql, args)?;
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Delete<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("DELETE FROM ");
-        self.0.build::<D>(sql, args);
+        self.0.build::<D>(sql, args)?;
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Set<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("SET ");
-        let mut elems = self.0.into_iter();
-        if let Some(elem) = elems.next() {
-            elem.0.build::<D>(sql, args);
+        assignments::<D>(self, sql, args)
+    }
+}
+
+fn assignments<'a, D: Dialect>(
+    set: crate::clause::Set<'a>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    let mut elems = set.0.into_iter();
+    if let Some(elem) = elems.next() {
+        elem.0.build::<D>(sql, args)?;
+        sql.push_str(" = ");
+        elem.1.build::<D>(sql, args)?;
+        for elem in elems {
+            sql.push_str(", ");
+            elem.0.build::<D>(sql, args)?;
             sql.push_str(" = ");
-            elem.1.build::<D>(sql, args);
-            for elem in elems {
-                sql.push_str(", ");
-                elem.0.build::<D>(sql, args);
-                sql.push_str(" = ");
-                elem.1.build::<D>(sql, args);
+            elem.1.build::<D>(sql, args)?;
+        }
+    }
+    Ok(())
+}
+
+impl<'a> ToSql<'a> for crate::clause::OnConflict<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        use crate::clause::ConflictAction;
+        use crate::clause::ConflictTarget;
+
+        // mysql and sqlite spell it in the verb of the `INSERT` statement.
+        if let ConflictAction::Replace = self.action {
+            return match D::SYNTAX {
+                Syntax::MySql | Syntax::Sqlite => Ok(()),
+                _ => Err(unsupported::<D>("REPLACE")),
+            };
+        }
+
+        // mysql has no conflict target, any unique key will do.
+        if let Syntax::MySql = D::SYNTAX {
+            return match (self.target, self.action) {
+                (_, ConflictAction::Update(_, Some(_))) => {
+                    Err(unsupported::<D>("ON CONFLICT DO UPDATE WHERE"))
+                }
+                (_, ConflictAction::Update(set, None)) => {
+                    sql.push_str("ON DUPLICATE KEY UPDATE ");
+                    assignments::<D>(set, sql, args)
+                }
+                (None, _) => Ok(()),
+                (Some(ConflictTarget::Columns(cols)), _) => match cols.first() {
+                    Some(col) => {
+                        sql.push_str("ON DUPLICATE KEY UPDATE ");
+                        join!(D, sql, args, " = ", [*col, *col]);
+                        Ok(())
+                    }
+                    None => Err(unsupported::<D>("ON CONFLICT () DO NOTHING")),
+                },
+                (Some(ConflictTarget::Constraint(_)), _) => {
+                    Err(unsupported::<D>("ON CONFLICT ON CONSTRAINT DO NOTHING"))
+                }
+            };
+        }
+
+        sql.push_str("ON CONFLICT");
+        match self.target {
+            None => {}
+            Some(ConflictTarget::Columns(cols)) => {
+                sql.push_str(" (");
+                join!(D, sql, args, ", ", cols);
+                sql.push(')');
+            }
+            Some(ConflictTarget::Constraint(_)) if D::SYNTAX == Syntax::Sqlite => {
+                return Err(unsupported::<D>("ON CONFLICT ON CONSTRAINT"));
+            }
+            Some(ConflictTarget::Constraint(name)) => {
+                sql.push_str(" ON CONSTRAINT ");
+                name.build::<D>(sql, args)?;
             }
         }
+        match self.action {
+            ConflictAction::Nothing => sql.push_str(" DO NOTHING"),
+            ConflictAction::Update(set, filter) => {
+                sql.push_str(" DO UPDATE ");
+                set.build::<D>(sql, args)?;
+                if let Some(filter) = filter {
+                    sql.push(' ');
+                    filter.build::<D>(sql, args)?;
+                }
+            }
+            ConflictAction::Replace => unreachable!(),
+        }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::With<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let ordered = self
+            .1
+            .iter()
+            .any(|cte| cte.search.is_some() || cte.cycle.is_some());
+        if ordered && !self.0 {
+            return Err(crate::error::Error::Invalid {
+                reason: "SEARCH or CYCLE outside of WITH RECURSIVE",
+            });
+        }
         if self.0 {
             sql.push_str("WITH RECURSIVE ");
         } else {
             sql.push_str("WITH ");
         }
         join!(D, sql, args, ", ", self.1);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Limit {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("LIMIT ");
-        crate::value::Value::from(self.0 as i64).build::<D>(sql, args);
+        crate::value::Value::from(self.0 as i64).build::<D>(sql, args)?;
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Offset {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push_str("OFFSET ");
-        crate::value::Value::from(self.0 as i64).build::<D>(sql, args);
+        crate::value::Value::from(self.0 as i64).build::<D>(sql, args)?;
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::Fetch {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+            if self.1 {
+                return Err(unsupported::<D>("FETCH FIRST WITH TIES"));
+            }
+            return crate::clause::Limit(self.0).build::<D>(sql, args);
+        }
+        sql.push_str("FETCH FIRST ");
+        crate::value::Value::from(self.0 as i64).build::<D>(sql, args)?;
+        if self.1 {
+            sql.push_str(" ROWS WITH TIES");
+        } else {
+            sql.push_str(" ROWS ONLY");
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::Lock<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        use crate::clause::LockStrength;
+        use crate::clause::LockWait;
+
+        match (D::SYNTAX, self.strength) {
+            (Syntax::Sqlite, _) => return Err(unsupported::<D>("row locking")),
+            (Syntax::MySql, LockStrength::Update | LockStrength::NoKeyUpdate) => {
+                sql.push_str("FOR UPDATE")
+            }
+            (Syntax::MySql, LockStrength::Share | LockStrength::KeyShare) => {
+                sql.push_str("FOR SHARE")
+            }
+            (_, LockStrength::Update) => sql.push_str("FOR UPDATE"),
+            (_, LockStrength::NoKeyUpdate) => sql.push_str("FOR NO KEY UPDATE"),
+            (_, LockStrength::Share) => sql.push_str("FOR SHARE"),
+            (_, LockStrength::KeyShare) => sql.push_str("FOR KEY SHARE"),
+        }
+        if !self.tables.is_empty() {
+            sql.push_str(" OF ");
+            join!(D, sql, args, ", ", self.tables);
+        }
+        match self.wait {
+            Some(LockWait::NoWait) => sql.push_str(" NOWAIT"),
+            Some(LockWait::SkipLocked) => sql.push_str(" SKIP LOCKED"),
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+/// Render a lock clause with its leading space, dropping optional locks the
+/// dialect can not express.
+pub(super) fn build_lock<'a, D: Dialect>(
+    lock: Option<crate::clause::Lock<'a>>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    match lock {
+        Some(lock) if lock.optional && D::SYNTAX == Syntax::Sqlite => Ok(()),
+        Some(lock) => {
+            sql.push(' ');
+            lock.build::<D>(sql, args)
+        }
+        None => Ok(()),
     }
 }
@@ -0,0 +1,934 @@
+use super::unsupported;
+use super::Dialect;
+use super::Syntax;
+use super::ToSql;
+
+impl<'a> ToSql<'a> for crate::ddl::Type<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        use crate::ddl::Type;
+
+        let syntax = D::SYNTAX;
+        match self {
+            Type::SmallInt => sql.push_str("SMALLINT"),
+            Type::Int if syntax == Syntax::MySql => sql.push_str("INT"),
+            Type::Int => sql.push_str("INTEGER"),
+            Type::BigInt => sql.push_str("BIGINT"),
+            Type::Real if syntax == Syntax::MySql => sql.push_str("FLOAT"),
+            Type::Real => sql.push_str("REAL"),
+            Type::Double => sql.push_str(match syntax {
+                Syntax::MySql => "DOUBLE",
+                Syntax::Sqlite => "REAL",
+                _ => "DOUBLE PRECISION",
+            }),
+            Type::Numeric(..) if syntax == Syntax::Sqlite => sql.push_str("NUMERIC"),
+            Type::Numeric(precision, scale) => {
+                sql.push_str(if syntax == Syntax::MySql {
+                    "DECIMAL"
+                } else {
+                    "NUMERIC"
+                });
+                sql.push_str(&format!("({precision}, {scale})"));
+            }
+            Type::Bool => sql.push_str("BOOLEAN"),
+            Type::Text => sql.push_str("TEXT"),
+            Type::Varchar(len) => sql.push_str(&format!("VARCHAR({len})")),
+            Type::Char(len) => sql.push_str(&format!("CHAR({len})")),
+            Type::Bytes => sql.push_str(match syntax {
+                Syntax::MySql | Syntax::Sqlite => "BLOB",
+                _ => "BYTEA",
+            }),
+            Type::Date => sql.push_str("DATE"),
+            Type::Time => sql.push_str("TIME"),
+            Type::Timestamp | Type::TimestampTz
+                if matches!(syntax, Syntax::MySql | Syntax::Sqlite) =>
+            {
+                sql.push_str("DATETIME")
+            }
+            Type::Timestamp => sql.push_str("TIMESTAMP"),
+            Type::TimestampTz => sql.push_str("TIMESTAMPTZ"),
+            Type::Uuid => sql.push_str(match syntax {
+                Syntax::MySql => "CHAR(36)",
+                Syntax::Sqlite => "TEXT",
+                _ => "UUID",
+            }),
+            Type::Json => sql.push_str(match syntax {
+                Syntax::Sqlite => "TEXT",
+                _ => "JSON",
+            }),
+            Type::Custom(name) => sql.push_str(name),
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::ddl::RefAction {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str(match self {
+            crate::ddl::RefAction::Cascade => "CASCADE",
+            crate::ddl::RefAction::SetNull => "SET NULL",
+            crate::ddl::RefAction::SetDefault => "SET DEFAULT",
+            crate::ddl::RefAction::Restrict => "RESTRICT",
+            crate::ddl::RefAction::NoAction => "NO ACTION",
+        });
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::ddl::Reference<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str("REFERENCES ");
+        self.table.build::<D>(sql, args)?;
+        sql.push_str(" (");
+        join!(D, sql, args, ", ", self.columns);
+        sql.push(')');
+        if let Some(action) = self.on_delete {
+            sql.push_str(" ON DELETE ");
+            action.build::<D>(sql, args)?;
+        }
+        if let Some(action) = self.on_update {
+            sql.push_str(" ON UPDATE ");
+            action.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::ddl::Column<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        use crate::ddl::Generated;
+        use crate::ddl::Type;
+
+        self.name.build::<D>(sql, args)?;
+        sql.push(' ');
+        let mut not_null = self.not_null;
+        let mut primary_key = self.primary_key;
+        let blob = matches!(self.ty, Type::Text | Type::Bytes | Type::Json);
+        match (self.generated, D::SYNTAX) {
+            (Some(Generated::Identity), Syntax::MySql) => {
+                self.ty.build::<D>(sql, args)?;
+                sql.push_str(" NOT NULL AUTO_INCREMENT");
+                not_null = false;
+            }
+            (Some(Generated::Identity), Syntax::Sqlite) => {
+                // only an `INTEGER PRIMARY KEY` can be auto incremented.
+                if !primary_key {
+                    return Err(unsupported::<D>("identity column without PRIMARY KEY"));
+                }
+                sql.push_str("INTEGER PRIMARY KEY AUTOINCREMENT");
+                primary_key = false;
+            }
+            (Some(Generated::Identity), Syntax::Generic)
+                if !matches!(self.ty, Type::SmallInt | Type::Int | Type::BigInt) =>
+            {
+                self.ty.build::<D>(sql, args)?;
+                sql.push_str(" GENERATED BY DEFAULT AS IDENTITY");
+            }
+            (Some(Generated::Identity), _) => sql.push_str(match self.ty {
+                Type::SmallInt => "SMALLSERIAL",
+                Type::Int => "SERIAL",
+                Type::BigInt => "BIGSERIAL",
+                _ => return Err(unsupported::<D>("identity column of non integer type")),
+            }),
+            (Some(Generated::Stored(expr)), _) => {
+                self.ty.build::<D>(sql, args)?;
+                sql.push_str(" GENERATED ALWAYS AS (");
+                expr.build::<D>(sql, args)?;
+                sql.push_str(") STORED");
+            }
+            (None, _) => self.ty.build::<D>(sql, args)?,
+        }
+        if not_null {
+            sql.push_str(" NOT NULL");
+        }
+        // mysql only takes literals as is, and none at all on blob types.
+        match self.default {
+            Some(default)
+                if D::SYNTAX == Syntax::MySql
+                    && (blob || !matches!(default, crate::expr::Expr::Literal(_))) =>
+            {
+                sql.push_str(" DEFAULT (");
+                default.build::<D>(sql, args)?;
+                sql.push(')');
+            }
+            Some(default) => {
+                sql.push_str(" DEFAULT ");
+                default.build::<D>(sql, args)?;
+            }
+            None => {}
+        }
+        if primary_key {
+            sql.push_str(" PRIMARY KEY");
+        }
+        if self.unique {
+            sql.push_str(" UNIQUE");
+        }
+        if let Some(check) = self.check {
+            sql.push_str(" CHECK (");
+            check.build::<D>(sql, args)?;
+            sql.push(')');
+        }
+        // mysql parses but ignores inline references, they are rendered as
+        // table constraints instead.
+        if let Some(references) = self.references {
+            if D::SYNTAX != Syntax::MySql {
+                sql.push(' ');
+                references.build::<D>(sql, args)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::ddl::Constraint<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        use crate::ddl::ConstraintKind;
+
+        if let Some(name) = self.name {
+            sql.push_str("CONSTRAINT ");
+            name.build::<D>(sql, args)?;
+            sql.push(' ');
+        }
+        match self.kind {
+            ConstraintKind::PrimaryKey(columns) => {
+                sql.push_str("PRIMARY KEY (");
+                join!(D, sql, args, ", ", columns);
+                sql.push(')');
+            }
+            ConstraintKind::Unique(columns) => {
+                sql.push_str("UNIQUE (");
+                join!(D, sql, args, ", ", columns);
+                sql.push(')');
+            }
+            ConstraintKind::Check(expr) => {
+                sql.push_str("CHECK (");
+                expr.build::<D>(sql, args)?;
+                sql.push(')');
+            }
+            ConstraintKind::ForeignKey(columns, references) => {
+                sql.push_str("FOREIGN KEY (");
+                join!(D, sql, args, ", ", columns);
+                sql.push_str(") ");
+                references.build::<D>(sql, args)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::create_table::CreateTable<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        // ddl does not take parameters.
+        let args = &mut Vec::new();
+        sql.push_str("CREATE TABLE ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        self.table.build::<D>(sql, args)?;
+        sql.push(' ');
+        definition::<D>(self.columns, self.constraints, sql)
+    }
+}
+
+/// Render `(columns, constraints)` of a table definition.
+fn definition<'a, D: Dialect>(
+    columns: Vec<crate::ddl::Column<'a>>,
+    constraints: Vec<crate::ddl::Constraint<'a>>,
+    sql: &mut String,
+) -> crate::error::Result<()> {
+    let args = &mut Vec::new();
+    let mut hoisted = Vec::new();
+    if D::SYNTAX == Syntax::MySql {
+        for column in columns.iter() {
+            if let Some(references) = column.references.clone() {
+                hoisted.push(crate::ddl::Constraint {
+                    name: None,
+                    kind: crate::ddl::ConstraintKind::ForeignKey(vec![column.name], references),
+                });
+            }
+        }
+    }
+    hoisted.extend(constraints);
+    sql.push('(');
+    join!(<D as Dialect>::Inline, sql, args, ", ", columns);
+    for constraint in hoisted {
+        sql.push_str(", ");
+        constraint.build::<<D as Dialect>::Inline>(sql, args)?;
+    }
+    sql.push(')');
+    Ok(())
+}
+
+impl<'a> ToSql<'a> for crate::ddl::AlterAction<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        use crate::ddl::AlterAction;
+
+        match self {
+            AlterAction::AddColumn(column) => {
+                sql.push_str("ADD COLUMN ");
+                column.build::<D>(sql, args)?;
+            }
+            AlterAction::DropColumn(column) => {
+                sql.push_str("DROP COLUMN ");
+                column.build::<D>(sql, args)?;
+            }
+            AlterAction::RenameColumn(from, to) => {
+                sql.push_str("RENAME COLUMN ");
+                join!(D, sql, args, from, "TO", to);
+            }
+            AlterAction::AlterType(column, ty) => {
+                match D::SYNTAX {
+                    Syntax::Sqlite => return Err(unsupported::<D>("ALTER COLUMN TYPE")),
+                    Syntax::MySql => sql.push_str("MODIFY COLUMN "),
+                    _ => sql.push_str("ALTER COLUMN "),
+                }
+                column.build::<D>(sql, args)?;
+                sql.push_str(if D::SYNTAX == Syntax::MySql {
+                    " "
+                } else {
+                    " TYPE "
+                });
+                ty.build::<D>(sql, args)?;
+            }
+            AlterAction::AddConstraint(_) | AlterAction::DropConstraint(_)
+                if D::SYNTAX == Syntax::Sqlite =>
+            {
+                return Err(unsupported::<D>("ALTER TABLE constraint"));
+            }
+            AlterAction::AddConstraint(constraint) => {
+                sql.push_str("ADD ");
+                constraint.build::<D>(sql, args)?;
+            }
+            AlterAction::DropConstraint(name) => {
+                sql.push_str("DROP CONSTRAINT ");
+                name.build::<D>(sql, args)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::alter_table::AlterTable<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let statements = alter_table::<D>(self)?;
+        // postgres and mysql can not prepare several statements at once.
+        if let (Syntax::Postgres | Syntax::MySql, true) = (D::SYNTAX, statements.len() > 1) {
+            return Err(unsupported::<D>("ALTER TABLE in several statements"));
+        }
+        sql.push_str(&statements.join("; "));
+        Ok(())
+    }
+}
+
+/// Render an `ALTER TABLE` into as few statements as dialect `D` allows.
+pub(crate) fn alter_table<D: Dialect>(
+    stmt: crate::stmt::alter_table::AlterTable,
+) -> crate::error::Result<Vec<String>> {
+    use crate::ddl::AlterAction;
+
+    let table = stmt.table;
+    let mut actions = Vec::with_capacity(stmt.actions.len());
+    for action in stmt.actions {
+        // mysql ignores inline references, add them as table constraints.
+        if let (AlterAction::AddColumn(column), Syntax::MySql) = (&action, D::SYNTAX) {
+            if let Some(references) = column.references.clone() {
+                let name = column.name;
+                actions.push(action);
+                actions.push(AlterAction::AddConstraint(crate::ddl::Constraint {
+                    name: None,
+                    kind: crate::ddl::ConstraintKind::ForeignKey(vec![name], references),
+                }));
+                continue;
+            }
+        }
+        actions.push(action);
+    }
+    if actions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    match D::SYNTAX {
+        Syntax::MySql => Ok(vec![alter::<D>(table, actions)?]),
+        Syntax::Sqlite if actions.iter().any(needs_rebuild) => {
+            let schema = stmt
+                .schema
+                .ok_or_else(|| unsupported::<D>("ALTER TABLE without schema"))?;
+            rebuild::<D>(table, actions, schema, stmt.indexes, !stmt.foreign_keys_off)
+        }
+        Syntax::Sqlite => actions
+            .into_iter()
+            .map(|action| alter::<D>(table, vec![action]))
+            .collect(),
+        _ => {
+            // renaming can not be combined with other actions.
+            let mut statements = Vec::new();
+            let mut pending = Vec::new();
+            for action in actions {
+                if let AlterAction::RenameColumn(..) = action {
+                    if !pending.is_empty() {
+                        statements.push(alter::<D>(table, std::mem::take(&mut pending))?);
+                    }
+                    statements.push(alter::<D>(table, vec![action])?);
+                } else {
+                    pending.push(action);
+                }
+            }
+            if !pending.is_empty() {
+                statements.push(alter::<D>(table, pending)?);
+            }
+            Ok(statements)
+        }
+    }
+}
+
+fn alter<'a, D: Dialect>(
+    table: crate::item::TableRef<'a>,
+    actions: Vec<crate::ddl::AlterAction<'a>>,
+) -> crate::error::Result<String> {
+    let sql = &mut String::from("ALTER TABLE ");
+    let args = &mut Vec::new();
+    table.build::<D>(sql, args)?;
+    sql.push(' ');
+    join!(<D as Dialect>::Inline, sql, args, ", ", actions);
+    Ok(std::mem::take(sql))
+}
+
+/// Whether sqlite has to rebuild the table to apply `action`.
+fn needs_rebuild(action: &crate::ddl::AlterAction) -> bool {
+    use crate::ddl::AlterAction;
+
+    match action {
+        // sqlite can not add a column with these constraints.
+        AlterAction::AddColumn(column) => {
+            column.primary_key || column.unique || column.generated.is_some()
+        }
+        AlterAction::DropColumn(_) | AlterAction::RenameColumn(..) => false,
+        AlterAction::AlterType(..)
+        | AlterAction::AddConstraint(_)
+        | AlterAction::DropConstraint(_) => true,
+    }
+}
+
+/// Sqlite table rebuild: create the altered table under a temporary name,
+/// copy the rows, drop the old table, rename the new one and recreate its
+/// indexes. All of it within a savepoint, checking foreign keys before
+/// releasing it. Enforced foreign keys are disabled meanwhile, so that
+/// dropping the table does not trigger their actions.
+fn rebuild<'a, D: Dialect>(
+    table: crate::item::TableRef<'a>,
+    actions: Vec<crate::ddl::AlterAction<'a>>,
+    schema: crate::stmt::create_table::CreateTable<'a>,
+    mut indexes: Vec<crate::stmt::create_index::CreateIndex<'a>>,
+    foreign_keys: bool,
+) -> crate::error::Result<Vec<String>> {
+    use crate::ddl::AlterAction;
+    use crate::ddl::ConstraintKind;
+    use crate::ddl::Generated;
+    use crate::item::Ident;
+    use crate::item::TableRef;
+
+    // every column with the column it is copied from, if any.
+    let mut columns: Vec<(Option<Ident>, crate::ddl::Column)> = schema
+        .columns
+        .into_iter()
+        .map(|column| (Some(column.name), column))
+        .collect();
+    let mut constraints = schema.constraints;
+    for action in actions {
+        match action {
+            AlterAction::AddColumn(column) => columns.push((None, *column)),
+            AlterAction::DropColumn(name) => columns.retain(|(_, column)| column.name != name),
+            AlterAction::RenameColumn(from, to) => {
+                let rename = |name: &mut Ident<'a>| {
+                    if *name == from {
+                        *name = to;
+                    }
+                };
+                columns
+                    .iter_mut()
+                    .for_each(|(_, column)| rename(&mut column.name));
+                for constraint in constraints.iter_mut() {
+                    match &mut constraint.kind {
+                        ConstraintKind::PrimaryKey(names)
+                        | ConstraintKind::Unique(names)
+                        | ConstraintKind::ForeignKey(names, _) => names.iter_mut().for_each(rename),
+                        ConstraintKind::Check(_) => {}
+                    }
+                }
+                for index in indexes.iter_mut() {
+                    for column in index.columns.iter_mut() {
+                        if let crate::expr::Expr::Column(crate::item::ColumnRef::Column(name)) =
+                            &mut column.0
+                        {
+                            rename(name);
+                        }
+                    }
+                }
+            }
+            AlterAction::AlterType(name, ty) => columns
+                .iter_mut()
+                .filter(|(_, column)| column.name == name)
+                .for_each(|(_, column)| column.ty = ty),
+            AlterAction::AddConstraint(constraint) => constraints.push(constraint),
+            AlterAction::DropConstraint(name) => {
+                constraints.retain(|constraint| constraint.name != Some(name))
+            }
+        }
+    }
+
+    let (schema_name, name) = match table {
+        TableRef::Table(name) => (None, name),
+        TableRef::SchemaTable(schema, name) => (Some(schema), name),
+    };
+    let args = &mut Vec::new();
+    let new = &mut String::new();
+    if let Some(schema) = schema_name {
+        schema.build::<D>(new, args)?;
+        new.push('.');
+    }
+    D::quote_ident(&format!("__new_{}", name.0), new);
+
+    let (targets, sources): (Vec<_>, Vec<_>) = columns
+        .iter()
+        .filter(|(_, column)| !matches!(column.generated, Some(Generated::Stored(_))))
+        .filter_map(|(source, column)| source.map(|source| (column.name, source)))
+        .unzip();
+
+    let create = &mut format!("CREATE TABLE {new} ");
+    definition::<D>(
+        columns.into_iter().map(|(_, column)| column).collect(),
+        constraints,
+        create,
+    )?;
+
+    let copy = &mut format!("INSERT INTO {new} (");
+    join!(D, copy, args, ", ", targets);
+    copy.push_str(") SELECT ");
+    join!(D, copy, args, ", ", sources);
+    copy.push_str(" FROM ");
+    table.build::<D>(copy, args)?;
+
+    let drop = &mut String::from("DROP TABLE ");
+    table.build::<D>(drop, args)?;
+
+    let rename = &mut format!("ALTER TABLE {new} RENAME TO ");
+    name.build::<D>(rename, args)?;
+
+    let savepoint = &mut String::new();
+    D::quote_ident(&format!("__alter_{}", name.0), savepoint);
+
+    let check = &mut String::from("PRAGMA ");
+    if let Some(schema) = schema_name {
+        schema.build::<D>(check, args)?;
+        check.push('.');
+    }
+    check.push_str("foreign_key_check");
+
+    let mut statements = Vec::new();
+    if foreign_keys {
+        statements.push("PRAGMA foreign_keys = OFF".to_string());
+    }
+    statements.extend([
+        format!("SAVEPOINT {savepoint}"),
+        std::mem::take(create),
+        std::mem::take(copy),
+        std::mem::take(drop),
+        std::mem::take(rename),
+    ]);
+    // dropping the table dropped its indexes.
+    for index in indexes {
+        let sql = &mut String::new();
+        index.build::<D>(sql, args)?;
+        statements.push(std::mem::take(sql));
+    }
+    statements.push(std::mem::take(check));
+    statements.push(format!("RELEASE {savepoint}"));
+    if foreign_keys {
+        statements.push("PRAGMA foreign_keys = ON".to_string());
+    }
+    Ok(statements)
+}
+
+impl<'a> ToSql<'a> for crate::stmt::create_index::CreateIndex<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let syntax = D::SYNTAX;
+        if self.concurrently && matches!(syntax, Syntax::MySql | Syntax::Sqlite) {
+            return Err(unsupported::<D>("CREATE INDEX CONCURRENTLY"));
+        }
+        if self.if_not_exists && syntax == Syntax::MySql {
+            return Err(unsupported::<D>("CREATE INDEX IF NOT EXISTS"));
+        }
+        if self.filter.is_some() && syntax == Syntax::MySql {
+            return Err(unsupported::<D>("partial index"));
+        }
+        if self.method.is_some() && syntax == Syntax::Sqlite {
+            return Err(unsupported::<D>("index method"));
+        }
+
+        // ddl does not take parameters.
+        let args = &mut Vec::new();
+        sql.push_str("CREATE ");
+        if self.unique {
+            sql.push_str("UNIQUE ");
+        }
+        sql.push_str("INDEX ");
+        if self.concurrently {
+            sql.push_str("CONCURRENTLY ");
+        }
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        self.name.build::<D>(sql, args)?;
+        sql.push_str(" ON ");
+        self.table.build::<D>(sql, args)?;
+        if let (Some(method), false) = (self.method, syntax == Syntax::MySql) {
+            sql.push_str(" USING ");
+            sql.push_str(method);
+        }
+        sql.push_str(" (");
+        let mut columns = self.columns.into_iter();
+        while let Some(crate::item::Order(expr, sort)) = columns.next() {
+            // expressions have to be parenthesized, mysql even requires it
+            // on function calls.
+            if let crate::expr::Expr::Column(_) = expr {
+                expr.build::<<D as Dialect>::Inline>(sql, args)?;
+            } else {
+                sql.push('(');
+                expr.build::<<D as Dialect>::Inline>(sql, args)?;
+                sql.push(')');
+            }
+            if let Some(sort) = sort {
+                sql.push(' ');
+                sort.build::<D>(sql, args)?;
+            }
+            if columns.len() > 0 {
+                sql.push_str(", ");
+            }
+        }
+        sql.push(')');
+        if let (Some(method), Syntax::MySql) = (self.method, syntax) {
+            sql.push_str(" USING ");
+            sql.push_str(method);
+        }
+        if let Some(filter) = self.filter {
+            sql.push_str(" WHERE ");
+            filter.build::<<D as Dialect>::Inline>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::drop_index::DropIndex<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let syntax = D::SYNTAX;
+        if self.concurrently && matches!(syntax, Syntax::MySql | Syntax::Sqlite) {
+            return Err(unsupported::<D>("DROP INDEX CONCURRENTLY"));
+        }
+        if syntax == Syntax::MySql && self.if_exists {
+            return Err(unsupported::<D>("DROP INDEX IF EXISTS"));
+        }
+        sql.push_str("DROP INDEX ");
+        if self.concurrently {
+            sql.push_str("CONCURRENTLY ");
+        }
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        self.name.build::<D>(sql, args)?;
+        if syntax == Syntax::MySql {
+            let table = self
+                .table
+                .ok_or_else(|| unsupported::<D>("DROP INDEX without table"))?;
+            sql.push_str(" ON ");
+            table.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::create_view::CreateView<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let syntax = D::SYNTAX;
+        if self.materialized && matches!(syntax, Syntax::MySql | Syntax::Sqlite) {
+            return Err(unsupported::<D>("materialized view"));
+        }
+        if self.materialized && self.or_replace && syntax == Syntax::Postgres {
+            return Err(unsupported::<D>("CREATE OR REPLACE MATERIALIZED VIEW"));
+        }
+        if self.or_replace && syntax == Syntax::Sqlite {
+            return Err(unsupported::<D>("CREATE OR REPLACE VIEW"));
+        }
+
+        // views do not take parameters.
+        let args = &mut Vec::new();
+        sql.push_str("CREATE ");
+        if self.or_replace {
+            sql.push_str("OR REPLACE ");
+        }
+        if self.materialized {
+            sql.push_str("MATERIALIZED ");
+        }
+        sql.push_str("VIEW ");
+        self.name.build::<D>(sql, args)?;
+        if !self.columns.is_empty() {
+            sql.push_str(" (");
+            join!(D, sql, args, ", ", self.columns);
+            sql.push(')');
+        }
+        sql.push_str(" AS ");
+        self.query.build::<<D as Dialect>::Inline>(sql, args)
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::drop_view::DropView<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if self.materialized && matches!(D::SYNTAX, Syntax::MySql | Syntax::Sqlite) {
+            return Err(unsupported::<D>("materialized view"));
+        }
+        sql.push_str(if self.materialized {
+            "DROP MATERIALIZED VIEW "
+        } else {
+            "DROP VIEW "
+        });
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        self.name.build::<D>(sql, args)
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::refresh_view::RefreshView<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if matches!(D::SYNTAX, Syntax::MySql | Syntax::Sqlite) {
+            return Err(unsupported::<D>("materialized view"));
+        }
+        sql.push_str("REFRESH MATERIALIZED VIEW ");
+        if self.concurrently {
+            sql.push_str("CONCURRENTLY ");
+        }
+        self.name.build::<D>(sql, args)
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::drop_table::DropTable<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if self.cascade && D::SYNTAX == Syntax::Sqlite {
+            return Err(unsupported::<D>("DROP TABLE CASCADE"));
+        }
+        sql.push_str("DROP TABLE ");
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        self.table.build::<D>(sql, args)?;
+        if self.cascade {
+            sql.push_str(" CASCADE");
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::truncate::Truncate<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        match D::SYNTAX {
+            Syntax::MySql | Syntax::Sqlite if self.cascade => {
+                return Err(unsupported::<D>("TRUNCATE CASCADE"));
+            }
+            // the auto increment counter lives in `sqlite_sequence`, which
+            // only exists once a table uses it.
+            Syntax::Sqlite if self.restart_identity => {
+                return Err(unsupported::<D>("TRUNCATE RESTART IDENTITY"));
+            }
+            Syntax::Sqlite => sql.push_str("DELETE FROM "),
+            // mysql always restarts the counter.
+            Syntax::MySql => sql.push_str("TRUNCATE TABLE "),
+            _ => sql.push_str("TRUNCATE "),
+        }
+        self.table.build::<D>(sql, args)?;
+        if self.restart_identity && D::SYNTAX != Syntax::MySql {
+            sql.push_str(" RESTART IDENTITY");
+        }
+        if self.cascade {
+            sql.push_str(" CASCADE");
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::create_schema::CreateSchema<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if D::SYNTAX == Syntax::Sqlite {
+            return Err(unsupported::<D>("CREATE SCHEMA"));
+        }
+        sql.push_str("CREATE SCHEMA ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        self.name.build::<D>(sql, args)
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::drop_schema::DropSchema<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if D::SYNTAX == Syntax::Sqlite {
+            return Err(unsupported::<D>("DROP SCHEMA"));
+        }
+        sql.push_str("DROP SCHEMA ");
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        self.name.build::<D>(sql, args)?;
+        // mysql drops the content of a schema anyway.
+        if self.cascade && D::SYNTAX != Syntax::MySql {
+            sql.push_str(" CASCADE");
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::create_enum::CreateEnum<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+            return Err(unsupported::<D>("CREATE TYPE AS ENUM"));
+        }
+        sql.push_str("CREATE TYPE ");
+        self.name.build::<D>(sql, args)?;
+        sql.push_str(" AS ENUM (");
+        for (idx, value) in self.values.into_iter().enumerate() {
+            if idx > 0 {
+                sql.push_str(", ");
+            }
+            D::quote_literal(value, sql);
+        }
+        sql.push(')');
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::alter_enum::AlterEnum<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+            return Err(unsupported::<D>("ALTER TYPE ADD VALUE"));
+        }
+        sql.push_str("ALTER TYPE ");
+        self.name.build::<D>(sql, args)?;
+        sql.push_str(" ADD VALUE ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        D::quote_literal(self.value, sql);
+        if let Some((position, value)) = self.position {
+            sql.push(' ');
+            sql.push_str(position);
+            sql.push(' ');
+            D::quote_literal(value, sql);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::create_sequence::CreateSequence<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+            return Err(unsupported::<D>("CREATE SEQUENCE"));
+        }
+        sql.push_str("CREATE SEQUENCE ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        self.name.build::<D>(sql, args)?;
+        let options = [
+            ("INCREMENT BY", self.increment),
+            ("MINVALUE", self.min_value),
+            ("MAXVALUE", self.max_value),
+            ("START WITH", self.start),
+        ];
+        for (option, value) in options {
+            if let Some(value) = value {
+                sql.push_str(&format!(" {option} {value}"));
+            }
+        }
+        if self.cycle {
+            sql.push_str(" CYCLE");
+        }
+        Ok(())
+    }
+}
@@ -1,40 +1,117 @@
 use super::Dialect;
+use super::Syntax;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::expr::Expr<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::expr::Expr::Literal(val) => val.build::<D>(sql, args),
-            crate::expr::Expr::Column(val) => val.build::<D>(sql, args),
-            crate::expr::Expr::FuncCall(val) => val.build::<D>(sql, args),
+            crate::expr::Expr::Literal(val) => val.build::<D>(sql, args)?,
+            crate::expr::Expr::Column(val) => val.build::<D>(sql, args)?,
+            crate::expr::Expr::FuncCall(val) => val.build::<D>(sql, args)?,
             crate::expr::Expr::Prefix(op, val) => {
                 sql.push_str(op);
                 sql.push(' ');
-                val.build::<D>(sql, args);
+                val.build::<D>(sql, args)?;
             }
             crate::expr::Expr::Infix(left, op, right) => {
-                left.build::<D>(sql, args);
+                left.build::<D>(sql, args)?;
                 sql.push(' ');
                 sql.push_str(op);
                 sql.push(' ');
-                right.build::<D>(sql, args);
+                right.build::<D>(sql, args)?;
             }
             crate::expr::Expr::Postfix(val, op) => {
-                val.build::<D>(sql, args);
+                val.build::<D>(sql, args)?;
                 sql.push(' ');
                 sql.push_str(op);
             }
             crate::expr::Expr::Paren(val) => {
                 sql.push('(');
-                val.build::<D>(sql, args);
+                val.build::<D>(sql, args)?;
+                sql.push(')');
+            }
+            crate::expr::Expr::Excluded(col) => {
+                if let Syntax::MySql = D::SYNTAX {
+                    sql.push_str("VALUES(");
+                    col.build::<D>(sql, args)?;
+                    sql.push(')');
+                } else {
+                    sql.push_str("excluded.");
+                    col.build::<D>(sql, args)?;
+                }
+            }
+            crate::expr::Expr::Default => {
+                if let Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("DEFAULT"));
+                }
+                sql.push_str("DEFAULT");
+            }
+            crate::expr::Expr::Rollup(exprs) => {
+                if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("ROLLUP"));
+                }
+                sql.push_str("ROLLUP (");
+                join!(D, sql, args, ", ", exprs);
+                sql.push(')');
+            }
+            crate::expr::Expr::Cube(exprs) => {
+                if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("CUBE"));
+                }
+                sql.push_str("CUBE (");
+                join!(D, sql, args, ", ", exprs);
+                sql.push(')');
+            }
+            crate::expr::Expr::GroupingSets(sets) => {
+                if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("GROUPING SETS"));
+                }
+                sql.push_str("GROUPING SETS (");
+                for (idx, set) in sets.into_iter().enumerate() {
+                    if idx > 0 {
+                        sql.push_str(", ");
+                    }
+                    sql.push('(');
+                    join!(D, sql, args, ", ", set);
+                    sql.push(')');
+                }
+                sql.push(')');
+            }
+            crate::expr::Expr::Grouping(exprs) => {
+                if let Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("GROUPING"));
+                }
+                sql.push_str("GROUPING(");
+                join!(D, sql, args, ", ", exprs);
+                sql.push(')');
+            }
+            crate::expr::Expr::Sequence(func, seq, value) => {
+                if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("sequence"));
+                }
+                // the sequence is given by name, as a text literal.
+                let name = &mut String::new();
+                seq.build::<D>(name, args)?;
+                sql.push_str(func);
+                sql.push('(');
+                D::quote_literal(name, sql);
+                if let Some(value) = value {
+                    sql.push_str(", ");
+                    value.build::<D>(sql, args)?;
+                }
                 sql.push(')');
             }
             crate::expr::Expr::SubQuery(val) => {
                 sql.push('(');
-                val.build::<D>(sql, args);
+                val.build::<D>(sql, args)?;
                 sql.push(')');
             }
         }
+        Ok(())
     }
 }
@@ -0,0 +1,239 @@
+//! Emulation of `ROLLUP`, `CUBE` and `GROUPING SETS` for dialects lacking
+//! them, by combining one plainly grouped select per grouping set with
+//! `UNION ALL`:
+//!
+//! ```sql
+//! SELECT a, b, count(*) FROM t GROUP BY a, b
+//! UNION ALL SELECT a, NULL AS b, count(*) FROM t GROUP BY a
+//! UNION ALL SELECT NULL AS a, NULL AS b, count(*) FROM t
+//! ```
+//!
+//! Columns missing from a grouping set become `NULL`, except inside
+//! aggregates, and `GROUPING(..)` becomes the constant it would have
+//! evaluated to. Rolled up columns passed to a function not known to be an
+//! aggregate, or to a window, are not supported.
+
+use super::unsupported;
+use super::Dialect;
+use super::Syntax;
+use super::ToSql;
+use crate::expr::Expr;
+
+/// Whether `groups` has to go through [`union`] for dialect `D`.
+pub(super) fn emulate<D: Dialect>(groups: &crate::clause::GroupBy) -> bool {
+    let extended = groups.0.iter().any(|group| {
+        matches!(
+            group,
+            Expr::Rollup(_) | Expr::Cube(_) | Expr::GroupingSets(_)
+        )
+    });
+    match D::SYNTAX {
+        Syntax::Sqlite => extended,
+        // a lone `ROLLUP` is rendered as `WITH ROLLUP`.
+        Syntax::MySql => extended && !matches!(groups.0.as_slice(), [Expr::Rollup(_)]),
+        _ => false,
+    }
+}
+
+pub(super) fn union<'a, D: Dialect>(
+    mut select: crate::stmt::select::Select<'a>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    if select.lock.is_some() {
+        return Err(unsupported::<D>("row locking with grouping sets"));
+    }
+    let sets = expand(
+        select
+            .groups
+            .take()
+            .map(|groups| groups.0)
+            .unwrap_or_default(),
+    );
+    let mut grouped = Vec::new();
+    for expr in sets.iter().flatten() {
+        if !grouped.contains(expr) {
+            grouped.push(expr.clone());
+        }
+    }
+    let orders = select.orders.take();
+
+    // a rolled up column is `NULL` in its branch, except inside aggregates.
+    // there is no telling whether an unknown function is an aggregate.
+    for set in sets.iter() {
+        let having = select.having.iter().map(|having| &having.0);
+        let exprs = select.fields.0.iter().map(|field| &field.expr);
+        let mut windows = select.windows.iter().flat_map(|windows| windows.0.iter());
+        if exprs.chain(having).any(|expr| opaque(expr, &grouped, set))
+            || windows.any(|(_, window)| rolled_up(window, &grouped, set))
+        {
+            return Err(unsupported::<D>(
+                "unknown function over rolled up columns in grouping sets",
+            ));
+        }
+    }
+
+    let mut branches = sets.into_iter().map(|set| {
+        let mut branch = select.clone();
+        for field in branch.fields.0.iter_mut() {
+            let expr = substitute(field.expr.clone(), &grouped, &set);
+            if let (None, Expr::Column(col)) = (&field.alias, &field.expr) {
+                if expr != field.expr {
+                    field.alias = Some((*col).into());
+                }
+            }
+            field.expr = expr;
+        }
+        if let Some(having) = branch.having.as_mut() {
+            having.0 = substitute(having.0.clone(), &grouped, &set);
+        }
+        if !set.is_empty() {
+            branch.groups = Some(crate::clause::GroupBy(set));
+        }
+        crate::stmt::result::Result::from(branch)
+    });
+    let first = match branches.next() {
+        Some(first) => first,
+        None => return Ok(()),
+    };
+    let mut combined = branches.fold(first, |left, right| {
+        crate::stmt::union_all(left, right).into()
+    });
+    match &mut combined.data {
+        crate::stmt::data::Data::Select(branch) => branch.orders = orders,
+        crate::stmt::data::Data::Binary(binary) => binary.orders = orders,
+        _ => unreachable!(),
+    }
+    combined.build::<D>(sql, args)
+}
+
+/// Grouping sets of a single grouping element.
+fn sets(group: Expr) -> Vec<Vec<Expr>> {
+    match group {
+        Expr::Rollup(exprs) => (0..=exprs.len())
+            .rev()
+            .map(|len| exprs[..len].to_vec())
+            .collect(),
+        Expr::Cube(exprs) => (0..1_usize << exprs.len())
+            .rev()
+            .map(|mask| {
+                exprs
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| mask & (1 << (exprs.len() - 1 - idx)) != 0)
+                    .map(|(_, expr)| expr.clone())
+                    .collect()
+            })
+            .collect(),
+        Expr::GroupingSets(sets) => sets,
+        expr => vec![vec![expr]],
+    }
+}
+
+/// Grouping sets of a whole `GROUP BY`, which is the cross product of the
+/// sets of its elements.
+fn expand(groups: Vec<Expr>) -> Vec<Vec<Expr>> {
+    groups.into_iter().fold(vec![Vec::new()], |acc, group| {
+        let sets = sets(group);
+        acc.iter()
+            .flat_map(|prefix| {
+                sets.iter().map(move |set| {
+                    let mut prefix = prefix.clone();
+                    prefix.extend(set.iter().cloned());
+                    prefix
+                })
+            })
+            .collect()
+    })
+}
+
+fn substitute<'a>(expr: Expr<'a>, grouped: &[Expr<'a>], set: &[Expr<'a>]) -> Expr<'a> {
+    match expr {
+        Expr::Grouping(exprs) => {
+            let len = exprs.len();
+            let bits = exprs
+                .iter()
+                .enumerate()
+                .filter(|(_, expr)| !set.contains(expr))
+                .fold(0_i32, |bits, (idx, _)| bits | 1 << (len - 1 - idx));
+            Expr::Literal(bits.into())
+        }
+        expr if grouped.contains(&expr) && !set.contains(&expr) => {
+            Expr::Literal(None::<i32>.into())
+        }
+        Expr::Prefix(op, val) => Expr::Prefix(op, Box::new(substitute(*val, grouped, set))),
+        Expr::Infix(left, op, right) => Expr::Infix(
+            Box::new(substitute(*left, grouped, set)),
+            op,
+            Box::new(substitute(*right, grouped, set)),
+        ),
+        Expr::Postfix(val, op) => Expr::Postfix(Box::new(substitute(*val, grouped, set)), op),
+        Expr::Paren(val) => Expr::Paren(Box::new(substitute(*val, grouped, set))),
+        // aggregates see every row of the group, grouped or not, unlike window
+        // functions which run over the grouped rows.
+        Expr::FuncCall(call) if is_aggregate(&call.0) && call.2.is_none() => Expr::FuncCall(call),
+        Expr::FuncCall(crate::item::FuncCall(func, exprs, over)) => {
+            Expr::FuncCall(crate::item::FuncCall(
+                func,
+                exprs
+                    .into_iter()
+                    .map(|expr| substitute(expr, grouped, set))
+                    .collect(),
+                over,
+            ))
+        }
+        expr => expr,
+    }
+}
+
+/// Whether `expr` passes a column missing from `set` to a function not known
+/// to be a plain aggregate, including windowed calls.
+fn opaque<'a>(expr: &Expr<'a>, grouped: &[Expr<'a>], set: &[Expr<'a>]) -> bool {
+    match expr {
+        Expr::Prefix(_, val) | Expr::Postfix(val, _) | Expr::Paren(val) => {
+            opaque(val, grouped, set)
+        }
+        Expr::Infix(left, _, right) => opaque(left, grouped, set) || opaque(right, grouped, set),
+        Expr::FuncCall(call) if is_aggregate(&call.0) && call.2.is_none() => false,
+        Expr::FuncCall(crate::item::FuncCall(_, exprs, over)) => {
+            let window = match over {
+                Some(crate::item::Over::Spec(window)) => rolled_up(window, grouped, set),
+                _ => false,
+            };
+            window || exprs.iter().any(|expr| missing(expr, grouped, set))
+        }
+        _ => false,
+    }
+}
+
+/// Whether `window` partitions or orders by a column missing from `set`.
+fn rolled_up<'a>(window: &crate::item::Window<'a>, grouped: &[Expr<'a>], set: &[Expr<'a>]) -> bool {
+    window
+        .partitions
+        .iter()
+        .chain(window.orders.iter().map(|order| &order.0))
+        .any(|expr| missing(expr, grouped, set))
+}
+
+fn missing<'a>(expr: &Expr<'a>, grouped: &[Expr<'a>], set: &[Expr<'a>]) -> bool {
+    substitute(expr.clone(), grouped, set) != *expr
+}
+
+fn is_aggregate(func: &crate::item::FuncRef) -> bool {
+    const AGGREGATES: &[&str] = &[
+        "avg",
+        "count",
+        "group_concat",
+        "max",
+        "min",
+        "string_agg",
+        "sum",
+        "total",
+    ];
+    match func {
+        crate::item::FuncRef::Func(name) => AGGREGATES
+            .iter()
+            .any(|aggregate| aggregate.eq_ignore_ascii_case(name.0)),
+        crate::item::FuncRef::SchemaFunc(..) => false,
+    }
+}
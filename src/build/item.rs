@@ -1,120 +1,417 @@
 use super::Dialect;
+use super::Syntax;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::value::Value<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         args.push(self);
         D::bind_param(args.len(), self, sql);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Ident<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         D::quote_ident(self.0, sql);
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Sort {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
             crate::item::Sort::Asc => sql.push_str("ASC"),
             crate::item::Sort::Desc => sql.push_str("DESC"),
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Order<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        self.0.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        self.0.build::<D>(sql, args)?;
         if let Some(sort) = self.1 {
             sql.push(' ');
-            sort.build::<D>(sql, args);
+            sort.build::<D>(sql, args)?;
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Field<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        self.expr.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        self.expr.build::<D>(sql, args)?;
         if let Some(alias) = self.alias {
             sql.push_str(" AS ");
-            alias.build::<D>(sql, args);
+            alias.build::<D>(sql, args)?;
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Table<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        self.table.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let has_types = self.columns.iter().any(|(_, ty)| ty.is_some());
+        if self.alias.is_none() && !self.columns.is_empty() && !has_types {
+            return Err(crate::error::Error::Invalid {
+                reason: "column aliases without a table alias",
+            });
+        }
+        if D::SYNTAX == Syntax::Sqlite && !self.columns.is_empty() && !has_types {
+            return alias_columns::<D>(self, sql, args);
+        }
+        self.table.build::<D>(sql, args)?;
+        if self.alias.is_none() && self.columns.is_empty() {
+            return Ok(());
+        }
+        sql.push_str(" AS ");
         if let Some(alias) = self.alias {
-            sql.push_str(" AS ");
-            alias.build::<D>(sql, args);
+            alias.build::<D>(sql, args)?;
+            if self.columns.is_empty() {
+                return Ok(());
+            }
+            sql.push(' ');
+        }
+        if let (true, Syntax::MySql | Syntax::Sqlite) = (has_types, D::SYNTAX) {
+            return Err(super::unsupported::<D>("column definition list"));
+        }
+        sql.push('(');
+        for (idx, (column, ty)) in self.columns.into_iter().enumerate() {
+            if idx > 0 {
+                sql.push_str(", ");
+            }
+            column.build::<D>(sql, args)?;
+            if let Some(ty) = ty {
+                sql.push(' ');
+                ty.build::<D>(sql, args)?;
+            }
+        }
+        sql.push(')');
+        Ok(())
+    }
+}
+
+/// Sqlite has no column aliases on tables, rename the columns inside the
+/// subquery instead. `VALUES` columns are named `column1`, `column2`, ...
+/// The aliases have to match the columns of the subquery one to one.
+fn alias_columns<'a, D: Dialect>(
+    table: crate::item::Table<'a>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    use crate::expr::Expr;
+    use crate::item::ColumnRef;
+    use crate::stmt::data::Data;
+    use crate::table_expr::TableExpr;
+
+    let columns = table.columns.into_iter().map(|(column, _)| column);
+    let mismatch = crate::error::Error::Invalid {
+        reason: "column aliases not matching the columns of the table",
+    };
+    match table.table {
+        TableExpr::SubQuery(Data::Values(values)) => {
+            if values.rows.0.iter().any(|row| row.0.len() != columns.len()) {
+                return Err(mismatch);
+            }
+            sql.push_str("(SELECT ");
+            for (idx, column) in columns.enumerate() {
+                if idx > 0 {
+                    sql.push_str(", ");
+                }
+                D::quote_ident(&format!("column{}", idx + 1), sql);
+                sql.push_str(" AS ");
+                column.build::<D>(sql, args)?;
+            }
+            sql.push_str(" FROM (");
+            values.build::<D>(sql, args)?;
+            sql.push_str("))");
+        }
+        TableExpr::SubQuery(Data::Select(mut select)) => {
+            // `*` expands to an unknown number of columns.
+            let wildcard = select.fields.0.iter().any(|field| match &field.expr {
+                Expr::Column(
+                    ColumnRef::Column(column)
+                    | ColumnRef::TableColumn(_, column)
+                    | ColumnRef::SchemaTableColumn(_, _, column),
+                ) => column.0 == "*",
+                _ => false,
+            });
+            if wildcard || select.fields.0.len() != columns.len() {
+                return Err(mismatch);
+            }
+            for (field, column) in select.fields.0.iter_mut().zip(columns) {
+                field.alias = Some(column);
+            }
+            TableExpr::SubQuery(Data::Select(select)).build::<D>(sql, args)?;
         }
+        _ => return Err(super::unsupported::<D>("table column aliases")),
     }
+    if let Some(alias) = table.alias {
+        sql.push_str(" AS ");
+        alias.build::<D>(sql, args)?;
+    }
+    Ok(())
 }
 
 impl<'a> ToSql<'a> for crate::item::ColumnRef<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::item::ColumnRef::Column(col) => col.build::<D>(sql, args),
+            crate::item::ColumnRef::Column(col) => col.build::<D>(sql, args)?,
             crate::item::ColumnRef::TableColumn(tbl, col) => join!(D, sql, args, ".", [tbl, col]),
             crate::item::ColumnRef::SchemaTableColumn(sch, tbl, col) => {
                 join!(D, sql, args, ".", [sch, tbl, col])
             }
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::FuncRef<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::item::FuncRef::Func(fun) => fun.build::<D>(sql, args),
+            crate::item::FuncRef::Func(fun) => fun.build::<D>(sql, args)?,
             crate::item::FuncRef::SchemaFunc(sch, fun) => join!(D, sql, args, ".", [sch, fun]),
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::TableRef<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::item::TableRef::Table(tbl) => tbl.build::<D>(sql, args),
+            crate::item::TableRef::Table(tbl) => tbl.build::<D>(sql, args)?,
             crate::item::TableRef::SchemaTable(sch, tbl) => join!(D, sql, args, ".", [sch, tbl]),
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::FuncCall<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        self.0.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        self.0.build::<D>(sql, args)?;
         sql.push('(');
         join!(D, sql, args, ", ", self.1);
         sql.push(')');
+        if let Some(over) = self.2 {
+            sql.push_str(" OVER ");
+            over.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Over<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        match self {
+            crate::item::Over::Name(name) => name.build::<D>(sql, args)?,
+            crate::item::Over::Spec(spec) => {
+                sql.push('(');
+                spec.build::<D>(sql, args)?;
+                sql.push(')');
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Window<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        let mut sep = "";
+        if let Some(base) = self.base {
+            base.build::<D>(sql, args)?;
+            sep = " ";
+        }
+        if !self.partitions.is_empty() {
+            sql.push_str(sep);
+            sql.push_str("PARTITION BY ");
+            join!(D, sql, args, ", ", self.partitions);
+            sep = " ";
+        }
+        if !self.orders.is_empty() {
+            sql.push_str(sep);
+            sql.push_str("ORDER BY ");
+            join!(D, sql, args, ", ", self.orders);
+            sep = " ";
+        }
+        if let Some(frame) = self.frame {
+            sql.push_str(sep);
+            frame.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Frame {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        _: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        sql.push_str(match self.0 {
+            crate::item::FrameUnit::Rows => "ROWS",
+            crate::item::FrameUnit::Range => "RANGE",
+            crate::item::FrameUnit::Groups => {
+                if let Syntax::MySql = D::SYNTAX {
+                    return Err(super::unsupported::<D>("GROUPS frame"));
+                }
+                "GROUPS"
+            }
+        });
+        for (idx, bound) in [self.1, self.2].into_iter().enumerate() {
+            sql.push_str(if idx == 0 { " BETWEEN " } else { " AND " });
+            match bound {
+                crate::item::FrameBound::UnboundedPreceding => sql.push_str("UNBOUNDED PRECEDING"),
+                crate::item::FrameBound::Preceding(n) => {
+                    sql.push_str(&n.to_string());
+                    sql.push_str(" PRECEDING");
+                }
+                crate::item::FrameBound::CurrentRow => sql.push_str("CURRENT ROW"),
+                crate::item::FrameBound::Following(n) => {
+                    sql.push_str(&n.to_string());
+                    sql.push_str(" FOLLOWING");
+                }
+                crate::item::FrameBound::UnboundedFollowing => sql.push_str("UNBOUNDED FOLLOWING"),
+            }
+        }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Row<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         sql.push('(');
         join!(D, sql, args, ", ", self.0);
         sql.push(')');
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Cte<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        self.name.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        self.name.build::<D>(sql, args)?;
         if !self.columns.is_empty() {
             sql.push('(');
             join!(D, sql, args, ", ", self.columns);
             sql.push(')');
         }
         sql.push_str(" AS ");
+        match self.materialized {
+            Some(_) if D::SYNTAX == Syntax::MySql => {
+                return Err(super::unsupported::<D>("MATERIALIZED CTE"));
+            }
+            Some(true) => sql.push_str("MATERIALIZED "),
+            Some(false) => sql.push_str("NOT MATERIALIZED "),
+            None => {}
+        }
         sql.push('(');
-        self.stmt.build::<D>(sql, args);
+        self.stmt.build::<D>(sql, args)?;
         sql.push(')');
+        if let Some(search) = self.search {
+            sql.push(' ');
+            search.build::<D>(sql, args)?;
+        }
+        if let Some(cycle) = self.cycle {
+            sql.push(' ');
+            cycle.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Search<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if matches!(D::SYNTAX, Syntax::MySql | Syntax::Sqlite) {
+            return Err(super::unsupported::<D>("CTE SEARCH"));
+        }
+        if self.breadth {
+            sql.push_str("SEARCH BREADTH FIRST BY ");
+        } else {
+            sql.push_str("SEARCH DEPTH FIRST BY ");
+        }
+        join!(D, sql, args, ", ", self.by);
+        sql.push_str(" SET ");
+        self.set.build::<D>(sql, args)
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Cycle<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if matches!(D::SYNTAX, Syntax::MySql | Syntax::Sqlite) {
+            return Err(super::unsupported::<D>("CTE CYCLE"));
+        }
+        sql.push_str("CYCLE ");
+        join!(D, sql, args, ", ", self.columns);
+        sql.push_str(" SET ");
+        self.set.build::<D>(sql, args)?;
+        sql.push_str(" USING ");
+        self.using.build::<D>(sql, args)
     }
 }
@@ -2,35 +2,65 @@ macro_rules! join {
     ($Dialect: ty, $sql:expr, $args:expr, $left:expr, $sep:expr, $right:expr, $cond:expr) => {{
         join!($Dialect, $sql, $args, $left, $sep, $right);
         $sql.push_str(" ON ");
-        $cond.build::<$Dialect>($sql, $args);
+        $cond.build::<$Dialect>($sql, $args)?;
     }};
     ($Dialect: ty, $sql:expr, $args:expr, $left:expr, $sep:expr, $right:expr) => {{
-        $left.build::<$Dialect>($sql, $args);
+        $left.build::<$Dialect>($sql, $args)?;
         $sql.push(' ');
         $sql.push_str($sep);
         $sql.push(' ');
-        $right.build::<$Dialect>($sql, $args);
+        $right.build::<$Dialect>($sql, $args)?;
     }};
 
     ($Dialect: ty, $sql:expr, $args:expr, $sep:expr, $elems:expr) => {{
         let mut elems = $elems.into_iter();
         if let Some(elem) = elems.next() {
-            elem.build::<$Dialect>($sql, $args);
+            elem.build::<$Dialect>($sql, $args)?;
             for elem in elems {
                 $sql.push_str($sep);
-                elem.build::<$Dialect>($sql, $args);
+                elem.build::<$Dialect>($sql, $args)?;
             }
         }
     }};
 }
 
 mod clause;
+mod ddl;
 mod expr;
+mod grouping;
 mod item;
 mod stmt;
 mod table_expr;
 
+pub(crate) use ddl::alter_table;
+
+/// SQL flavour a [`Dialect`] renders.
+#[allow(dead_code)] // only constructed by the sqlx backends
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Syntax {
+    Generic,
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl Syntax {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Syntax::Generic => "generic",
+            Syntax::Postgres => "postgres",
+            Syntax::MySql => "mysql",
+            Syntax::Sqlite => "sqlite",
+        }
+    }
+}
+
 pub trait Dialect {
+    const SYNTAX: Syntax;
+
+    /// This dialect with literals inlined, usually [`Inline<Self>`].
+    type Inline: Dialect;
+
     fn quote_literal(val: &str, buff: &mut String);
 
     fn quote_ident(name: &str, buff: &mut String);
@@ -43,19 +73,84 @@ pub trait Dialect {
 }
 
 pub(crate) trait ToSql<'a>: Sized {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>);
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()>;
 
-    fn to_sql<D: Dialect>(self) -> (String, Vec<crate::value::Value<'a>>) {
+    fn to_sql<D: Dialect>(self) -> crate::error::Result<(String, Vec<crate::value::Value<'a>>)> {
         let mut sql = String::new();
         let mut args = Vec::new();
-        self.build::<D>(&mut sql, &mut args);
-        (sql, args)
+        self.build::<D>(&mut sql, &mut args)?;
+        Ok((sql, args))
+    }
+}
+
+/// Error for `feature` that dialect `D` can not render.
+pub(crate) fn unsupported<D: Dialect>(feature: &'static str) -> crate::error::Error {
+    crate::error::Error::Unsupported {
+        dialect: D::SYNTAX.name(),
+        feature,
+    }
+}
+
+/// Dialect `D` with literals inlined instead of bound, for statements not
+/// accepting parameters such as DDL.
+#[derive(Debug)]
+pub struct Inline<D>(std::marker::PhantomData<D>);
+
+impl<D: Dialect> Dialect for Inline<D> {
+    const SYNTAX: Syntax = D::SYNTAX;
+
+    type Inline = Self;
+
+    fn quote_literal(val: &str, buff: &mut String) {
+        D::quote_literal(val, buff)
+    }
+
+    fn quote_ident(name: &str, buff: &mut String) {
+        D::quote_ident(name, buff)
+    }
+
+    fn bind_param<'a>(
+        n: usize,
+        val: crate::value::Value<'a>,
+        buff: &mut String,
+    ) -> crate::value::Value<'a> {
+        match val {
+            crate::value::Value::Text(text) => {
+                D::quote_literal(text, buff);
+                val
+            }
+            crate::value::Value::Bytes(bytes) if D::SYNTAX != Syntax::Generic => {
+                use std::fmt::Write;
+
+                buff.push_str(match D::SYNTAX {
+                    Syntax::Postgres => "'\\x",
+                    _ => "X'",
+                });
+                for &byte in bytes.iter() {
+                    let _ = write!(buff, "{:02x}", byte);
+                }
+                buff.push('\'');
+                if D::SYNTAX == Syntax::Postgres {
+                    buff.push_str("::bytea");
+                }
+                val
+            }
+            val => Display::bind_param(n, val, buff),
+        }
     }
 }
 
 pub(crate) struct Display;
 
 impl Dialect for Display {
+    const SYNTAX: Syntax = Syntax::Generic;
+
+    type Inline = Inline<Self>;
+
     fn quote_literal(val: &str, buff: &mut String) {
         buff.push('\'');
         for ch in val.chars() {
@@ -112,17 +207,80 @@ impl Dialect for Display {
                 buff.push('"');
             }
             #[cfg(feature = "use-chrono")]
+            crate::value::Value::NaiveDate(val) => {
+                let _ = write!(buff, "'{}'", val.format("%F"));
+            }
+            #[cfg(feature = "use-chrono")]
+            crate::value::Value::NaiveTime(val) => {
+                let _ = write!(buff, "'{}'", val.format("%T%.f"));
+            }
+            #[cfg(feature = "use-chrono")]
+            crate::value::Value::NaiveDateTime(val) => {
+                let _ = write!(buff, "'{}'", val.format("%F %T%.f"));
+            }
+            #[cfg(feature = "use-chrono")]
             crate::value::Value::DateTime(val) => {
                 buff.push('\'');
                 let _ = write!(buff, "{val}");
                 buff.push('\'');
             }
+            #[cfg(feature = "use-chrono")]
+            crate::value::Value::FixedDateTime(val) => {
+                let _ = write!(buff, "'{}'", val.format("%F %T%.f%:z"));
+            }
+
+            #[cfg(feature = "use-time")]
+            crate::value::Value::Date(val) => {
+                let _ = write!(buff, "'{}'", val.format("%F"));
+            }
+            #[cfg(feature = "use-time")]
+            crate::value::Value::Time(val) => {
+                let _ = write!(buff, "'{}{}'", val.format("%T"), Fraction(val.nanosecond()));
+            }
+            #[cfg(feature = "use-time")]
+            crate::value::Value::PrimitiveDateTime(val) => {
+                let _ = write!(
+                    buff,
+                    "'{}{}'",
+                    val.format("%F %T"),
+                    Fraction(val.nanosecond())
+                );
+            }
+            #[cfg(feature = "use-time")]
+            crate::value::Value::OffsetDateTime(val) => {
+                let _ = write!(
+                    buff,
+                    "'{}{} {}'",
+                    val.format("%F %T"),
+                    Fraction(val.nanosecond()),
+                    val.format("%z")
+                );
+            }
 
             #[cfg(feature = "use-decimal")]
             crate::value::Value::Numeric(val) => {
                 let _ = write!(buff, "{val}");
             }
+
+            crate::value::Value::Custom(val) => val.write_literal(buff),
         };
         val
     }
 }
+
+/// Fractional seconds the way chrono's `%.f` renders them: nothing when
+/// zero, otherwise a dot followed by 3, 6 or 9 digits.
+#[cfg(feature = "use-time")]
+pub(crate) struct Fraction(pub(crate) u32);
+
+#[cfg(feature = "use-time")]
+impl std::fmt::Display for Fraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => Ok(()),
+            n if n % 1_000_000 == 0 => write!(f, ".{:03}", n / 1_000_000),
+            n if n % 1_000 == 0 => write!(f, ".{:06}", n / 1_000),
+            n => write!(f, ".{:09}", n),
+        }
+    }
+}
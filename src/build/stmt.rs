@@ -1,152 +1,673 @@
+use super::unsupported;
 use super::Dialect;
+use super::Syntax;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::stmt::Stmt<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::stmt::Stmt::Insert(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::Stmt::Select(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::Stmt::Update(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::Stmt::Delete(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::Stmt::Values(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::Stmt::Binary(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::Stmt::Result(stmt) => stmt.build::<D>(sql, args),
+            crate::stmt::Stmt::Insert(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Select(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Update(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Delete(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Merge(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::CreateTable(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::AlterTable(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::CreateIndex(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::DropIndex(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::CreateView(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::DropView(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::RefreshView(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::DropTable(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Truncate(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::CreateSchema(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::DropSchema(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::CreateEnum(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::AlterEnum(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::CreateSequence(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Explain(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Values(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Binary(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::Stmt::Result(stmt) => stmt.build::<D>(sql, args)?,
         }
+        Ok(())
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::explain::Explain<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        match D::SYNTAX {
+            Syntax::Sqlite => {
+                if self.analyze || self.buffers || self.json {
+                    return Err(unsupported::<D>("EXPLAIN options"));
+                }
+                sql.push_str("EXPLAIN QUERY PLAN ");
+            }
+            Syntax::MySql => {
+                if self.buffers {
+                    return Err(unsupported::<D>("EXPLAIN BUFFERS"));
+                }
+                if self.analyze && self.json {
+                    return Err(unsupported::<D>("EXPLAIN ANALYZE FORMAT=JSON"));
+                }
+                sql.push_str("EXPLAIN ");
+                if self.analyze {
+                    sql.push_str("ANALYZE ");
+                }
+                if self.json {
+                    sql.push_str("FORMAT=JSON ");
+                }
+            }
+            Syntax::Generic | Syntax::Postgres => {
+                sql.push_str("EXPLAIN ");
+                let options = [
+                    (self.analyze, "ANALYZE"),
+                    (self.buffers, "BUFFERS"),
+                    (self.json, "FORMAT JSON"),
+                ];
+                let mut options = options.iter().filter(|(on, _)| *on).map(|(_, opt)| *opt);
+                if let Some(opt) = options.next() {
+                    sql.push('(');
+                    sql.push_str(opt);
+                    for opt in options {
+                        sql.push_str(", ");
+                        sql.push_str(opt);
+                    }
+                    sql.push_str(") ");
+                }
+            }
+        }
+        self.stmt.build::<D>(sql, args)
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::binary::Binary<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        self.left.build::<D>(sql, args);
+        self.left.build::<D>(sql, args)?;
         sql.push(' ');
         sql.push_str(self.op);
         sql.push(' ');
-        self.right.build::<D>(sql, args);
+        self.right.build::<D>(sql, args)?;
+        if let Some(orders) = self.orders {
+            sql.push(' ');
+            orders.build::<D>(sql, args)?;
+        }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::select::Select<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+    fn build<D: Dialect>(
+        mut self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if let Some(with) = self.with.take() {
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        macro_rules! build_option {
-            ($name:ident) => {
-                if let Some($name) = self.$name {
-                    sql.push(' ');
-                    $name.build::<D>(sql, args);
-                };
+        if let Some(true) = self.groups.as_ref().map(super::grouping::emulate::<D>) {
+            return super::grouping::union::<D>(self, sql, args);
+        }
+        match self.distinct.take() {
+            Some(distinct) if !distinct.0.is_empty() && emulate_distinct_on::<D>() => {
+                return distinct_on::<D>(distinct, self, sql, args);
+            }
+            Some(distinct) => {
+                sql.push_str("SELECT ");
+                distinct.build::<D>(sql, args)?;
+                sql.push(' ');
+                join!(D, sql, args, ", ", self.fields.0);
+            }
+            None => self.fields.build::<D>(sql, args)?,
+        }
+        build_tail::<D>(
+            self.tables,
+            self.filter,
+            self.groups,
+            self.having,
+            self.windows,
+            sql,
+            args,
+        )?;
+        if let Some(orders) = self.orders {
+            sql.push(' ');
+            orders.build::<D>(sql, args)?;
+        }
+        super::clause::build_lock::<D>(self.lock, sql, args)
+    }
+}
+
+fn build_tail<'a, D: Dialect>(
+    tables: Option<crate::clause::From<'a>>,
+    filter: Option<crate::clause::Where<'a>>,
+    groups: Option<crate::clause::GroupBy<'a>>,
+    having: Option<crate::clause::Having<'a>>,
+    windows: Option<crate::clause::Window<'a>>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    macro_rules! build_option {
+        ($name:ident) => {
+            if let Some($name) = $name {
+                sql.push(' ');
+                $name.build::<D>(sql, args)?;
             };
+        };
+    }
+    build_option!(tables);
+    build_option!(filter);
+    build_option!(groups);
+    build_option!(having);
+    build_option!(windows);
+    Ok(())
+}
+
+fn emulate_distinct_on<D: Dialect>() -> bool {
+    matches!(D::SYNTAX, Syntax::MySql | Syntax::Sqlite)
+}
+
+/// Emulate `DISTINCT ON` by numbering rows of each distinct group and keeping
+/// the first one:
+///
+/// ```sql
+/// SELECT a, b FROM (
+///     SELECT a, b, ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) AS __rn, b AS __o1
+///     FROM ...
+/// ) AS __distinct WHERE __rn = 1 ORDER BY __o1
+/// ```
+fn distinct_on<'a, D: Dialect>(
+    distinct: crate::clause::Distinct<'a>,
+    select: crate::stmt::select::Select<'a>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    let names = select
+        .fields
+        .0
+        .iter()
+        .map(|field| match (&field.alias, &field.expr) {
+            (Some(alias), _) => Ok(*alias),
+            (None, crate::expr::Expr::Column(col)) => Ok((*col).into()),
+            _ => Err(unsupported::<D>("DISTINCT ON with unnamed field")),
+        })
+        .collect::<crate::error::Result<Vec<crate::item::Ident>>>()?;
+    let orders = select.orders.map(|orders| orders.0).unwrap_or_default();
+
+    sql.push_str("SELECT ");
+    join!(D, sql, args, ", ", names);
+    sql.push_str(" FROM (");
+    select.fields.build::<D>(sql, args)?;
+    sql.push_str(", ROW_NUMBER() OVER (PARTITION BY ");
+    join!(D, sql, args, ", ", distinct.0);
+    if !orders.is_empty() {
+        sql.push_str(" ORDER BY ");
+        join!(D, sql, args, ", ", orders.iter().cloned());
+    }
+    sql.push_str(") AS ");
+    D::quote_ident("__rn", sql);
+    for (idx, order) in orders.iter().enumerate() {
+        sql.push_str(", ");
+        order.0.clone().build::<D>(sql, args)?;
+        sql.push_str(" AS ");
+        D::quote_ident(format!("__o{}", idx + 1).as_str(), sql);
+    }
+    build_tail::<D>(
+        select.tables,
+        select.filter,
+        select.groups,
+        select.having,
+        select.windows,
+        sql,
+        args,
+    )?;
+    sql.push_str(") AS ");
+    D::quote_ident("__distinct", sql);
+    sql.push_str(" WHERE ");
+    D::quote_ident("__rn", sql);
+    sql.push_str(" = 1");
+    if !orders.is_empty() {
+        sql.push_str(" ORDER BY ");
+        for (idx, order) in orders.into_iter().enumerate() {
+            if idx > 0 {
+                sql.push_str(", ");
+            }
+            D::quote_ident(format!("__o{}", idx + 1).as_str(), sql);
+            if let Some(sort) = order.1 {
+                sql.push(' ');
+                sort.build::<D>(sql, args)?;
+            }
         }
-        self.fields.build::<D>(sql, args);
-        build_option!(tables);
-        build_option!(filter);
-        build_option!(groups);
-        build_option!(having);
-        build_option!(orders);
     }
+    super::clause::build_lock::<D>(select.lock, sql, args)
 }
 
 impl<'a> ToSql<'a> for crate::stmt::insert::Insert<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        self.table.build::<D>(sql, args);
+        let (verb, conflict) = insert_verb::<D>(self.conflict)?;
+        let (table, values) = match D::SYNTAX {
+            Syntax::Sqlite => strip_defaults::<D>(self.table, self.values)?,
+            _ => (self.table, self.values),
+        };
+        table.build_with::<D>(verb, sql, args)?;
         sql.push(' ');
-        self.values.build::<D>(sql, args);
+        match values {
+            crate::stmt::data::Data::Values(values) if values.rows.0 == [Default::default()] => {
+                match D::SYNTAX {
+                    Syntax::MySql => sql.push_str("VALUES ()"),
+                    _ => sql.push_str("DEFAULT VALUES"),
+                }
+            }
+            crate::stmt::data::Data::Values(values) if values.with.is_none() => {
+                values.rows.build::<D>(sql, args)?
+            }
+            values => values.build::<D>(sql, args)?,
+        }
+        if let Some(conflict) = conflict {
+            sql.push(' ');
+            conflict.build::<D>(sql, args)?;
+        }
         if let Some(returns) = self.returns {
             sql.push(' ');
-            returns.build::<D>(sql, args);
+            returns.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sqlite has no `DEFAULT` inside `VALUES`, so columns left to their default
+/// in every row are dropped from the statement instead.
+fn strip_defaults<'a, D: Dialect>(
+    mut table: crate::clause::Insert<'a>,
+    mut values: crate::stmt::data::Data<'a>,
+) -> crate::error::Result<(crate::clause::Insert<'a>, crate::stmt::data::Data<'a>)> {
+    if let crate::stmt::data::Data::Values(inner) = &mut values {
+        let rows = &mut inner.rows.0;
+        for idx in (0..table.1.len()).rev() {
+            let defaults = rows
+                .iter()
+                .filter(|row| row.0.get(idx) == Some(&crate::expr::Expr::Default))
+                .count();
+            if defaults == 0 {
+                continue;
+            }
+            if defaults != rows.len() {
+                return Err(unsupported::<D>("DEFAULT in some rows of VALUES"));
+            }
+            table.1.remove(idx);
+            for row in rows.iter_mut() {
+                row.0.remove(idx);
+            }
+        }
+        if table.1.is_empty() && rows.len() > 1 && rows.iter().all(|row| row.0.is_empty()) {
+            return Err(unsupported::<D>("DEFAULT VALUES with multiple rows"));
         }
     }
+    Ok((table, values))
+}
+
+/// Some dialects spell conflict handling in the verb of an `INSERT` statement
+/// instead of a trailing clause. Returns the verb and whatever is left to render.
+fn insert_verb<D: Dialect>(
+    conflict: Option<crate::clause::OnConflict<'_>>,
+) -> crate::error::Result<(&'static str, Option<crate::clause::OnConflict<'_>>)> {
+    use crate::clause::ConflictAction;
+
+    let conflict = match conflict {
+        Some(conflict)
+            if conflict.target.is_none() || matches!(conflict.action, ConflictAction::Replace) =>
+        {
+            conflict
+        }
+        conflict => return Ok(("INSERT INTO", conflict)),
+    };
+    match (D::SYNTAX, &conflict.action) {
+        (Syntax::MySql, ConflictAction::Nothing) => Ok(("INSERT IGNORE INTO", None)),
+        (Syntax::Sqlite, ConflictAction::Nothing) => Ok(("INSERT OR IGNORE INTO", None)),
+        (Syntax::Sqlite, ConflictAction::Replace) => Ok(("INSERT OR REPLACE INTO", None)),
+        (Syntax::Postgres, ConflictAction::Replace) => Err(unsupported::<D>("REPLACE")),
+        (_, ConflictAction::Replace) => Ok(("REPLACE INTO", None)),
+        _ => Ok(("INSERT INTO", Some(conflict))),
+    }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::update::Update<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        self.table.build::<D>(sql, args);
+        let table = self.table.0;
+        self.table.build::<D>(sql, args)?;
+        let mut filter = self.filter;
+        let joined = self.from.is_some();
+        let from = match (D::SYNTAX, self.from) {
+            // UPDATE t JOIN other ON filter SET ...
+            (Syntax::MySql, Some(from)) => {
+                if self.orders.is_some() || self.limit.is_some() {
+                    return Err(unsupported::<D>("ORDER BY or LIMIT in multi-table UPDATE"));
+                }
+                for table in from.0 {
+                    sql.push_str(" JOIN ");
+                    table.build::<D>(sql, args)?;
+                }
+                if let Some(filter) = filter.take() {
+                    sql.push_str(" ON ");
+                    filter.0.build::<D>(sql, args)?;
+                }
+                None
+            }
+            (_, from) => from,
+        };
         sql.push(' ');
-        self.set.build::<D>(sql, args);
-        if let Some(from) = self.from {
-            sql.push(' ');
-            from.build::<D>(sql, args);
+        match (D::SYNTAX, joined) {
+            // joined tables may have columns of the same name.
+            (Syntax::MySql, true) => {
+                sql.push_str("SET ");
+                for (idx, (col, val)) in self.set.0.into_iter().enumerate() {
+                    if idx > 0 {
+                        sql.push_str(", ");
+                    }
+                    match table {
+                        crate::item::TableRef::Table(table) => {
+                            crate::item::ColumnRef::TableColumn(table, col)
+                        }
+                        crate::item::TableRef::SchemaTable(schema, table) => {
+                            crate::item::ColumnRef::SchemaTableColumn(schema, table, col)
+                        }
+                    }
+                    .build::<D>(sql, args)?;
+                    sql.push_str(" = ");
+                    val.build::<D>(sql, args)?;
+                }
+            }
+            _ => self.set.build::<D>(sql, args)?,
         }
-        if let Some(filter) = self.filter {
+        if let Some(from) = from {
+            if self.limit.is_some() && D::SYNTAX == Syntax::Sqlite {
+                return Err(unsupported::<D>("UPDATE FROM with LIMIT"));
+            }
             sql.push(' ');
-            filter.build::<D>(sql, args);
+            from.build::<D>(sql, args)?;
         }
+        build_limited::<D>(table, filter, self.orders, self.limit, sql, args)?;
         if let Some(returns) = self.returns {
             sql.push(' ');
-            returns.build::<D>(sql, args);
+            returns.build::<D>(sql, args)?;
         }
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::delete::Delete<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        self.table.build::<D>(sql, args);
-        if let Some(filter) = self.filter {
-            sql.push(' ');
-            filter.build::<D>(sql, args);
+        let table = self.table.0;
+        let mut filter = self.filter;
+        match (D::SYNTAX, self.using) {
+            (_, None) => self.table.build::<D>(sql, args)?,
+            (Syntax::MySql, Some(using)) => {
+                if self.orders.is_some() || self.limit.is_some() {
+                    return Err(unsupported::<D>("ORDER BY or LIMIT in multi-table DELETE"));
+                }
+                sql.push_str("DELETE ");
+                table.build::<D>(sql, args)?;
+                sql.push_str(" FROM ");
+                table.build::<D>(sql, args)?;
+                for table in using.0 {
+                    sql.push_str(" JOIN ");
+                    table.build::<D>(sql, args)?;
+                }
+            }
+            (Syntax::Sqlite, Some(using)) => {
+                // WHERE EXISTS (SELECT 1 FROM using WHERE filter)
+                self.table.build::<D>(sql, args)?;
+                let mut exists = crate::stmt::select([1]);
+                exists.tables = Some(using);
+                exists.filter = filter.take();
+                filter = Some(
+                    crate::expr::Expr::Prefix(
+                        "EXISTS",
+                        Box::new(crate::expr::Expr::SubQuery(exists.into())),
+                    )
+                    .into(),
+                );
+            }
+            (_, Some(using)) => {
+                self.table.build::<D>(sql, args)?;
+                sql.push_str(" USING ");
+                join!(D, sql, args, ", ", using.0);
+            }
         }
+        build_limited::<D>(table, filter, self.orders, self.limit, sql, args)?;
         if let Some(returns) = self.returns {
             sql.push(' ');
-            returns.build::<D>(sql, args);
+            returns.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
+/// Render `WHERE ... ORDER BY ... LIMIT ...` of `UPDATE` and `DELETE`.
+///
+/// Sqlite only has it behind a compile time option, so it is emulated with:
+///
+/// ```sql
+/// WHERE rowid IN (SELECT rowid FROM table WHERE ... ORDER BY ... LIMIT ...)
+/// ```
+fn build_limited<'a, D: Dialect>(
+    table: crate::item::TableRef<'a>,
+    filter: Option<crate::clause::Where<'a>>,
+    orders: Option<crate::clause::OrderBy<'a>>,
+    limit: Option<crate::clause::Limit>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    match (D::SYNTAX, limit) {
+        (Syntax::Postgres, _) if orders.is_some() || limit.is_some() => {
+            Err(unsupported::<D>("ORDER BY or LIMIT in UPDATE and DELETE"))
+        }
+        (Syntax::Sqlite, Some(limit)) => {
+            sql.push_str(" WHERE rowid IN (SELECT rowid FROM ");
+            table.build::<D>(sql, args)?;
+            if let Some(filter) = filter {
+                sql.push(' ');
+                filter.build::<D>(sql, args)?;
+            }
+            if let Some(orders) = orders {
+                sql.push(' ');
+                orders.build::<D>(sql, args)?;
+            }
+            sql.push(' ');
+            limit.build::<D>(sql, args)?;
+            sql.push(')');
+            Ok(())
+        }
+        (Syntax::Sqlite, None) => {
+            // ordering does not matter without limit.
+            if let Some(filter) = filter {
+                sql.push(' ');
+                filter.build::<D>(sql, args)?;
+            }
+            Ok(())
+        }
+        _ => {
+            if let Some(filter) = filter {
+                sql.push(' ');
+                filter.build::<D>(sql, args)?;
+            }
+            if let Some(orders) = orders {
+                sql.push(' ');
+                orders.build::<D>(sql, args)?;
+            }
+            if let Some(limit) = limit {
+                sql.push(' ');
+                limit.build::<D>(sql, args)?;
+            }
+            Ok(())
         }
     }
 }
 
+impl<'a> ToSql<'a> for crate::stmt::merge::Merge<'a> {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
+        if let Syntax::MySql | Syntax::Sqlite = D::SYNTAX {
+            return Err(unsupported::<D>("MERGE"));
+        }
+        if self.arms.is_empty() {
+            return Err(crate::error::Error::Invalid {
+                reason: "MERGE without WHEN clause",
+            });
+        }
+        if let Some(with) = self.with {
+            with.build::<D>(sql, args)?;
+            sql.push(' ')
+        }
+        sql.push_str("MERGE INTO ");
+        self.target.build::<D>(sql, args)?;
+        sql.push_str(" USING ");
+        self.source.build::<D>(sql, args)?;
+        sql.push_str(" ON ");
+        self.cond.build::<D>(sql, args)?;
+        for arm in self.arms {
+            sql.push(' ');
+            arm.build::<D>(sql, args)?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a> ToSql<'a> for crate::stmt::values::Values<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        self.rows.build::<D>(sql, args);
+        // mysql needs `ROW(..)` outside of `INSERT`.
+        if D::SYNTAX == Syntax::MySql {
+            sql.push_str("VALUES ");
+            for (idx, row) in self.rows.0.into_iter().enumerate() {
+                if idx > 0 {
+                    sql.push_str(", ");
+                }
+                sql.push_str("ROW");
+                row.build::<D>(sql, args)?;
+            }
+            return Ok(());
+        }
+        self.rows.build::<D>(sql, args)?;
+        Ok(())
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::result::Result<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         if let Some(with) = self.with {
-            with.build::<D>(sql, args);
+            with.build::<D>(sql, args)?;
             sql.push(' ')
         }
-        self.data.build::<D>(sql, args);
-        if let Some(limit) = self.limit {
+        // locking comes after `LIMIT`, so pull it out of the select, the
+        // outer one taking precedence.
+        let mut data = self.data;
+        let inner = match &mut data {
+            crate::stmt::data::Data::Select(select) => select.lock.take(),
+            _ => None,
+        };
+        let lock = self.lock.or(inner);
+        data.build::<D>(sql, args)?;
+        // mysql and sqlite spell `FETCH FIRST` as `LIMIT`, which must come
+        // before `OFFSET`, and can not have `OFFSET` without `LIMIT`.
+        let (mut limit, mut fetch) = (self.limit, self.fetch);
+        if let (Syntax::MySql | Syntax::Sqlite, Some(inner)) = (D::SYNTAX, fetch) {
+            if inner.1 {
+                return Err(unsupported::<D>("FETCH FIRST WITH TIES"));
+            }
+            limit = Some(crate::clause::Limit(inner.0));
+            fetch = None;
+        }
+        if let Some(limit) = limit {
             sql.push(' ');
-            limit.build::<D>(sql, args);
+            limit.build::<D>(sql, args)?;
+        } else if self.offset.is_some() {
+            match D::SYNTAX {
+                Syntax::MySql => sql.push_str(" LIMIT 18446744073709551615"),
+                Syntax::Sqlite => sql.push_str(" LIMIT -1"),
+                _ => {}
+            }
         }
         if let Some(offset) = self.offset {
             sql.push(' ');
-            offset.build::<D>(sql, args);
+            offset.build::<D>(sql, args)?;
+        }
+        if let Some(fetch) = fetch {
+            sql.push(' ');
+            fetch.build::<D>(sql, args)?;
         }
+        super::clause::build_lock::<D>(lock, sql, args)
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::data::Data<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::stmt::data::Data::Select(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::data::Data::Values(stmt) => stmt.build::<D>(sql, args),
-            crate::stmt::data::Data::Binary(stmt) => stmt.build::<D>(sql, args),
+            crate::stmt::data::Data::Select(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::data::Data::Values(stmt) => stmt.build::<D>(sql, args)?,
+            crate::stmt::data::Data::Binary(stmt) => stmt.build::<D>(sql, args)?,
         }
+        Ok(())
     }
 }
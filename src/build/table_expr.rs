@@ -3,10 +3,14 @@ use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::table_expr::TableExpr<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(
+        self,
+        sql: &mut String,
+        args: &mut Vec<crate::value::Value<'a>>,
+    ) -> crate::error::Result<()> {
         match self {
-            crate::table_expr::TableExpr::TableRef(val) => val.build::<D>(sql, args),
-            crate::table_expr::TableExpr::FuncCall(val) => val.build::<D>(sql, args),
+            crate::table_expr::TableExpr::TableRef(val) => val.build::<D>(sql, args)?,
+            crate::table_expr::TableExpr::FuncCall(val) => val.build::<D>(sql, args)?,
             crate::table_expr::TableExpr::Join(left, right, cond) => {
                 join!(D, sql, args, left, "JOIN", right, cond)
             }
@@ -34,11 +38,69 @@ impl<'a> ToSql<'a> for crate::table_expr::TableExpr<'a> {
             crate::table_expr::TableExpr::CrossJoin(left, right) => {
                 join!(D, sql, args, left, "CROSS JOIN", right)
             }
+            crate::table_expr::TableExpr::JoinUsing(left, right, cols) => {
+                join!(D, sql, args, left, "JOIN", right);
+                using::<D>(cols, sql, args)?;
+            }
+            crate::table_expr::TableExpr::LeftJoinUsing(left, right, cols) => {
+                join!(D, sql, args, left, "LEFT JOIN", right);
+                using::<D>(cols, sql, args)?;
+            }
+            crate::table_expr::TableExpr::RightJoinUsing(left, right, cols) => {
+                join!(D, sql, args, left, "RIGHT JOIN", right);
+                using::<D>(cols, sql, args)?;
+            }
+            crate::table_expr::TableExpr::FullJoinUsing(left, right, cols) => {
+                join!(D, sql, args, left, "FULL JOIN", right);
+                using::<D>(cols, sql, args)?;
+            }
+            crate::table_expr::TableExpr::Lateral(val, alias) => {
+                if let super::Syntax::Sqlite = D::SYNTAX {
+                    return Err(super::unsupported::<D>("LATERAL"));
+                }
+                sql.push_str("LATERAL (");
+                val.build::<D>(sql, args)?;
+                sql.push_str(") AS ");
+                alias.build::<D>(sql, args)?;
+            }
+            crate::table_expr::TableExpr::RowsFrom(funcs, ordinality) => {
+                if let super::Syntax::MySql | super::Syntax::Sqlite = D::SYNTAX {
+                    if ordinality {
+                        return Err(super::unsupported::<D>("WITH ORDINALITY"));
+                    }
+                    if funcs.len() != 1 {
+                        return Err(super::unsupported::<D>("ROWS FROM"));
+                    }
+                }
+                if funcs.len() == 1 {
+                    join!(D, sql, args, ", ", funcs);
+                } else {
+                    sql.push_str("ROWS FROM (");
+                    join!(D, sql, args, ", ", funcs);
+                    sql.push(')');
+                }
+                if ordinality {
+                    sql.push_str(" WITH ORDINALITY");
+                }
+            }
+            crate::table_expr::TableExpr::Aliased(val) => val.build::<D>(sql, args)?,
             crate::table_expr::TableExpr::SubQuery(val) => {
                 sql.push('(');
-                val.build::<D>(sql, args);
+                val.build::<D>(sql, args)?;
                 sql.push(')');
             }
         }
+        Ok(())
     }
 }
+
+fn using<'a, D: Dialect>(
+    cols: Vec<crate::item::Ident<'a>>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) -> crate::error::Result<()> {
+    sql.push_str(" USING (");
+    join!(D, sql, args, ", ", cols);
+    sql.push(')');
+    Ok(())
+}
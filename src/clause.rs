@@ -43,6 +43,17 @@ crate::macros::gen_impl_from_arr!(Select[Field]<'a>);
 crate::macros::gen_impl_from_vec!(Select[Field]<'a>);
 crate::macros::gen_impl_from_tup!(Select[Field]<'a>);
 
+/// Represent a `DISTINCT` modifier of `SELECT` clause, `DISTINCT ON (exprs)`
+/// when it has expressions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Distinct<'a>(pub(crate) Vec<Expr<'a>>);
+
+crate::macros::gen_display!(Distinct<'_>);
+crate::macros::gen_impl_from_arr!(Distinct[Expr]<'a>);
+crate::macros::gen_impl_from_vec!(Distinct[Expr]<'a>);
+crate::macros::gen_impl_from_tup!(Distinct[Expr]<'a>);
+
 /// Represent a `FROM` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[repr(transparent)]
@@ -98,6 +109,13 @@ crate::macros::gen_display!(GroupBy<'_>);
 crate::macros::gen_impl_from_arr!(GroupBy[Expr]<'a>);
 crate::macros::gen_impl_from_vec!(GroupBy[Expr]<'a>);
 
+/// Represent a `WINDOW` clause, pairs of name and spec.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Window<'a>(pub(crate) Vec<(Ident<'a>, crate::item::Window<'a>)>);
+
+crate::macros::gen_display!(Window<'_>);
+
 /// Represent a `HAVING` clause.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[repr(transparent)]
@@ -229,6 +247,51 @@ where
     }
 }
 
+/// Represent an `ON CONFLICT` clause inside `INSERT` statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OnConflict<'a> {
+    pub(crate) target: Option<ConflictTarget<'a>>,
+    pub(crate) action: ConflictAction<'a>,
+}
+
+crate::macros::gen_display!(OnConflict<'_>);
+
+/// What an [`OnConflict`] clause is checking.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConflictTarget<'a> {
+    Columns(Vec<Ident<'a>>),
+    Constraint(Ident<'a>),
+}
+
+/// What an [`OnConflict`] clause is doing on conflict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConflictAction<'a> {
+    Nothing,
+    Update(Set<'a>, Option<Where<'a>>),
+    Replace,
+}
+
+/// Represent a `WHEN [NOT] MATCHED [AND cond] THEN action` arm of `MERGE`
+/// statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeWhen<'a> {
+    pub(crate) matched: bool,
+    pub(crate) cond: Option<Expr<'a>>,
+    pub(crate) action: MergeAction<'a>,
+}
+
+crate::macros::gen_display!(MergeWhen<'_>);
+
+/// What a [`MergeWhen`] arm is doing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MergeAction<'a> {
+    Update(Set<'a>),
+    Delete,
+    /// Columns and values, `DEFAULT VALUES` when both are empty.
+    Insert(Vec<Ident<'a>>, Row<'a>),
+    Nothing,
+}
+
 /// Represent a `LIMIT` clause.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(transparent)]
@@ -256,6 +319,109 @@ impl std::convert::From<u32> for Offset {
         Offset(val)
     }
 }
+
+/// Represent a `FETCH FIRST n ROWS ONLY` clause, `WITH TIES` instead of `ONLY`
+/// when the flag is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fetch(pub(crate) u32, pub(crate) bool);
+
+crate::macros::gen_display!(Fetch);
+
+impl std::convert::From<u32> for Fetch {
+    #[inline]
+    fn from(val: u32) -> Self {
+        Fetch(val, false)
+    }
+}
+
+/// Represent a row locking clause, `FOR UPDATE` and friends.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lock<'a> {
+    pub(crate) strength: LockStrength,
+    pub(crate) tables: Vec<Ident<'a>>,
+    pub(crate) wait: Option<LockWait>,
+    pub(crate) optional: bool,
+}
+
+crate::macros::gen_display!(Lock<'_>);
+
+/// Strength of a [`Lock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockStrength {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+/// What a [`Lock`] does with rows already locked by others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockWait {
+    NoWait,
+    SkipLocked,
+}
+
+impl<'a> Lock<'a> {
+    #[inline]
+    fn new(strength: LockStrength) -> Lock<'a> {
+        Lock {
+            strength,
+            tables: Vec::new(),
+            wait: None,
+            optional: false,
+        }
+    }
+
+    /// `FOR UPDATE`
+    pub fn update() -> Lock<'a> {
+        Lock::new(LockStrength::Update)
+    }
+
+    /// `FOR NO KEY UPDATE`, rendered as `FOR UPDATE` on mysql.
+    pub fn no_key_update() -> Lock<'a> {
+        Lock::new(LockStrength::NoKeyUpdate)
+    }
+
+    /// `FOR SHARE`
+    pub fn share() -> Lock<'a> {
+        Lock::new(LockStrength::Share)
+    }
+
+    /// `FOR KEY SHARE`, rendered as `FOR SHARE` on mysql.
+    pub fn key_share() -> Lock<'a> {
+        Lock::new(LockStrength::KeyShare)
+    }
+
+    /// Only lock rows coming from `tables`.
+    pub fn of<I, T>(mut self, tables: I) -> Lock<'a>
+    where
+        T: Into<Ident<'a>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.tables.extend(tables.into_iter().map(Into::into));
+        self
+    }
+
+    /// Fail instead of waiting for locked rows.
+    pub fn nowait(mut self) -> Lock<'a> {
+        self.wait = Some(LockWait::NoWait);
+        self
+    }
+
+    /// Skip locked rows instead of waiting for them.
+    pub fn skip_locked(mut self) -> Lock<'a> {
+        self.wait = Some(LockWait::SkipLocked);
+        self
+    }
+
+    /// Silently drop the lock on dialects without row locks (sqlite) instead
+    /// of failing to build the statement.
+    pub fn optional(mut self) -> Lock<'a> {
+        self.optional = true;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::clause::From;
@@ -311,6 +477,7 @@ mod tests {
             clause,
             From(vec![Table {
                 alias: None,
+                columns: Vec::new(),
                 table: TableExpr::TableRef(TableRef::Table(Ident("user"))),
             }])
         );
@@ -322,10 +489,12 @@ mod tests {
             From(vec![
                 Table {
                     alias: None,
+                    columns: Vec::new(),
                     table: TableExpr::TableRef(TableRef::Table(Ident("user")))
                 },
                 Table {
                     alias: None,
+                    columns: Vec::new(),
                     table: TableExpr::TableRef(TableRef::SchemaTable(
                         Ident("public"),
                         Ident("contact")
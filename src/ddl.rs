@@ -0,0 +1,343 @@
+//! Building blocks of DDL statements: column types, column definitions and
+//! table constraints.
+//!
+//! Expressions inside DDL (`DEFAULT`, `CHECK`, generated columns) are
+//! rendered with their literals inlined, since DDL does not accept bound
+//! parameters.
+
+use crate::expr::Expr;
+use crate::item::Ident;
+use crate::item::TableRef;
+
+/// Portable column type, mapped to the closest native type of each dialect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type<'a> {
+    SmallInt,
+    Int,
+    BigInt,
+    Real,
+    Double,
+    /// `NUMERIC(precision, scale)`
+    Numeric(u8, u8),
+    Bool,
+    Text,
+    Varchar(u32),
+    Char(u32),
+    Bytes,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Uuid,
+    Json,
+    /// Native type name, rendered as is.
+    Custom(&'a str),
+}
+
+crate::macros::gen_display!(Type<'_>);
+
+/// Referential action of `ON DELETE` and `ON UPDATE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefAction {
+    Cascade,
+    SetNull,
+    SetDefault,
+    Restrict,
+    NoAction,
+}
+
+/// `REFERENCES table (columns) [ON DELETE ..] [ON UPDATE ..]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference<'a> {
+    pub(crate) table: TableRef<'a>,
+    pub(crate) columns: Vec<Ident<'a>>,
+    pub(crate) on_delete: Option<RefAction>,
+    pub(crate) on_update: Option<RefAction>,
+}
+
+crate::macros::gen_display!(Reference<'_>);
+
+/// How a generated column gets its value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Generated<'a> {
+    /// Auto incremented integer, `SERIAL`, `AUTO_INCREMENT` or
+    /// `AUTOINCREMENT` depending on the dialect.
+    Identity,
+    /// `GENERATED ALWAYS AS (expr) STORED`
+    Stored(Expr<'a>),
+}
+
+/// Column definition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Column<'a> {
+    pub(crate) name: Ident<'a>,
+    pub(crate) ty: Type<'a>,
+    pub(crate) not_null: bool,
+    pub(crate) default: Option<Expr<'a>>,
+    pub(crate) primary_key: bool,
+    pub(crate) unique: bool,
+    pub(crate) check: Option<Expr<'a>>,
+    pub(crate) references: Option<Reference<'a>>,
+    pub(crate) generated: Option<Generated<'a>>,
+}
+
+crate::macros::gen_display!(Column<'_>);
+
+/// Construct a column definition.
+///
+/// # Examples
+///
+/// ```
+/// use xql::ddl::{column, Type};
+///
+/// let untitled = "untitled".to_string();
+/// assert_eq!(
+///     column("title", Type::Varchar(200)).not_null().default(&untitled).to_string(),
+///     "title VARCHAR(200) NOT NULL DEFAULT 'untitled'",
+/// );
+/// ```
+#[inline]
+pub fn column<'a, N>(name: N, ty: Type<'a>) -> Column<'a>
+where
+    N: Into<Ident<'a>>,
+{
+    Column {
+        name: name.into(),
+        ty,
+        not_null: false,
+        default: None,
+        primary_key: false,
+        unique: false,
+        check: None,
+        references: None,
+        generated: None,
+    }
+}
+
+impl<'a> Column<'a> {
+    /// Add `NOT NULL` constraint.
+    pub fn not_null(mut self) -> Column<'a> {
+        self.not_null = true;
+        self
+    }
+
+    /// Set `DEFAULT` value.
+    pub fn default<E>(mut self, expr: E) -> Column<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.default = Some(expr.into());
+        self
+    }
+
+    /// Add `PRIMARY KEY` constraint.
+    pub fn primary_key(mut self) -> Column<'a> {
+        self.primary_key = true;
+        self
+    }
+
+    /// Add `UNIQUE` constraint.
+    pub fn unique(mut self) -> Column<'a> {
+        self.unique = true;
+        self
+    }
+
+    /// Add `CHECK` constraint.
+    pub fn check<E>(mut self, expr: E) -> Column<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.check = Some(expr.into());
+        self
+    }
+
+    /// Add `REFERENCES table (column)` constraint.
+    ///
+    /// Mysql ignores inline references, so it is rendered there as a
+    /// `FOREIGN KEY` table constraint.
+    pub fn references<T, C>(mut self, table: T, column: C) -> Column<'a>
+    where
+        T: Into<TableRef<'a>>,
+        C: Into<Ident<'a>>,
+    {
+        self.references = Some(Reference {
+            table: table.into(),
+            columns: vec![column.into()],
+            on_delete: None,
+            on_update: None,
+        });
+        self
+    }
+
+    /// Set `ON DELETE` action of the reference.
+    pub fn on_delete(mut self, action: RefAction) -> Column<'a> {
+        if let Some(references) = self.references.as_mut() {
+            references.on_delete = Some(action);
+        }
+        self
+    }
+
+    /// Set `ON UPDATE` action of the reference.
+    pub fn on_update(mut self, action: RefAction) -> Column<'a> {
+        if let Some(references) = self.references.as_mut() {
+            references.on_update = Some(action);
+        }
+        self
+    }
+
+    /// Make an auto incremented integer column.
+    ///
+    /// Sqlite only has it on `INTEGER PRIMARY KEY`, so it must be combined
+    /// with [`primary_key`](Column::primary_key) there.
+    pub fn identity(mut self) -> Column<'a> {
+        self.generated = Some(Generated::Identity);
+        self
+    }
+
+    /// Make a stored column computed from `expr`.
+    pub fn generated<E>(mut self, expr: E) -> Column<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.generated = Some(Generated::Stored(expr.into()));
+        self
+    }
+}
+
+/// Table constraint, optionally named.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint<'a> {
+    pub(crate) name: Option<Ident<'a>>,
+    pub(crate) kind: ConstraintKind<'a>,
+}
+
+crate::macros::gen_display!(Constraint<'_>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConstraintKind<'a> {
+    PrimaryKey(Vec<Ident<'a>>),
+    Unique(Vec<Ident<'a>>),
+    Check(Expr<'a>),
+    ForeignKey(Vec<Ident<'a>>, Reference<'a>),
+}
+
+impl<'a> Constraint<'a> {
+    /// Name the constraint, `CONSTRAINT name ...`.
+    pub fn named<N>(mut self, name: N) -> Constraint<'a>
+    where
+        N: Into<Ident<'a>>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set `ON DELETE` action of a foreign key.
+    pub fn on_delete(mut self, action: RefAction) -> Constraint<'a> {
+        if let ConstraintKind::ForeignKey(_, references) = &mut self.kind {
+            references.on_delete = Some(action);
+        }
+        self
+    }
+
+    /// Set `ON UPDATE` action of a foreign key.
+    pub fn on_update(mut self, action: RefAction) -> Constraint<'a> {
+        if let ConstraintKind::ForeignKey(_, references) = &mut self.kind {
+            references.on_update = Some(action);
+        }
+        self
+    }
+}
+
+#[inline]
+fn constraint(kind: ConstraintKind) -> Constraint {
+    Constraint { name: None, kind }
+}
+
+/// Construct a (possibly composite) `PRIMARY KEY` table constraint.
+///
+/// # Examples
+///
+/// ```
+/// use xql::ddl::primary_key;
+///
+/// assert_eq!(
+///     primary_key(["book_id", "tag"]).to_string(),
+///     "PRIMARY KEY (book_id, tag)",
+/// );
+/// ```
+#[inline]
+pub fn primary_key<'a, I, C>(columns: I) -> Constraint<'a>
+where
+    C: Into<Ident<'a>>,
+    I: IntoIterator<Item = C>,
+{
+    constraint(ConstraintKind::PrimaryKey(
+        columns.into_iter().map(Into::into).collect(),
+    ))
+}
+
+/// Construct a `UNIQUE` table constraint.
+#[inline]
+pub fn unique<'a, I, C>(columns: I) -> Constraint<'a>
+where
+    C: Into<Ident<'a>>,
+    I: IntoIterator<Item = C>,
+{
+    constraint(ConstraintKind::Unique(
+        columns.into_iter().map(Into::into).collect(),
+    ))
+}
+
+/// Construct a `CHECK` table constraint.
+#[inline]
+pub fn check<'a, E>(expr: E) -> Constraint<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    constraint(ConstraintKind::Check(expr.into()))
+}
+
+/// Construct a `FOREIGN KEY` table constraint.
+///
+/// # Examples
+///
+/// ```
+/// use xql::ddl::{foreign_key, RefAction};
+///
+/// assert_eq!(
+///     foreign_key(["author_id"], "author", ["id"]).named("book_author").on_delete(RefAction::Cascade).to_string(),
+///     "CONSTRAINT book_author FOREIGN KEY (author_id) REFERENCES author (id) ON DELETE CASCADE",
+/// );
+/// ```
+#[inline]
+pub fn foreign_key<'a, I, C, T, R, D>(columns: I, table: T, references: R) -> Constraint<'a>
+where
+    C: Into<Ident<'a>>,
+    I: IntoIterator<Item = C>,
+    T: Into<TableRef<'a>>,
+    D: Into<Ident<'a>>,
+    R: IntoIterator<Item = D>,
+{
+    constraint(ConstraintKind::ForeignKey(
+        columns.into_iter().map(Into::into).collect(),
+        Reference {
+            table: table.into(),
+            columns: references.into_iter().map(Into::into).collect(),
+            on_delete: None,
+            on_update: None,
+        },
+    ))
+}
+
+/// Action of an `ALTER TABLE` statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterAction<'a> {
+    AddColumn(Box<Column<'a>>),
+    DropColumn(Ident<'a>),
+    RenameColumn(Ident<'a>, Ident<'a>),
+    AlterType(Ident<'a>, Type<'a>),
+    AddConstraint(Constraint<'a>),
+    DropConstraint(Ident<'a>),
+}
+
+crate::macros::gen_display!(AlterAction<'_>);
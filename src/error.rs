@@ -0,0 +1,26 @@
+/// Error raised while rendering a statement for a specific dialect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The statement uses a feature the target dialect can not express.
+    Unsupported {
+        dialect: &'static str,
+        feature: &'static str,
+    },
+    /// The statement is malformed, whatever the dialect.
+    Invalid { reason: &'static str },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unsupported { dialect, feature } => {
+                write!(f, "{feature} is not supported by {dialect}")
+            }
+            Error::Invalid { reason } => write!(f, "invalid statement: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
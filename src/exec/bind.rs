@@ -109,6 +109,79 @@ pub trait Bind: Database {
         Q: Query<'q, Self>;
 }
 
+/// An object safe [`Encode`](sqlx::Encode) used to bind
+/// [`CustomValue`](crate::value::CustomValue).
+///
+/// Implemented for every type that implements both [`Encode`](sqlx::Encode)
+/// and [`Type`](sqlx::Type).
+#[cfg(feature = "sqlx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+pub trait Encoder<DB: Database>: Sync {
+    fn encode<'q>(
+        &self,
+        buf: &mut <DB as sqlx::database::HasArguments<'q>>::ArgumentBuffer,
+    ) -> sqlx::encode::IsNull;
+
+    fn type_info(&self) -> DB::TypeInfo;
+}
+
+#[cfg(feature = "sqlx")]
+impl<DB, T> Encoder<DB> for T
+where
+    DB: Database,
+    T: Sync + for<'q> sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+{
+    fn encode<'q>(
+        &self,
+        buf: &mut <DB as sqlx::database::HasArguments<'q>>::ArgumentBuffer,
+    ) -> sqlx::encode::IsNull {
+        sqlx::Encode::<'q, DB>::encode_by_ref(self, buf)
+    }
+
+    fn type_info(&self) -> DB::TypeInfo {
+        sqlx::Encode::<'_, DB>::produces(self).unwrap_or_else(T::type_info)
+    }
+}
+
+/// Adapts an [`Encoder`] back into [`Encode`](sqlx::Encode) so it can be
+/// passed to `bind`.
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+struct Custom<'q, DB: Database>(&'q dyn Encoder<DB>);
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+impl<'q, DB: Database> sqlx::Encode<'q, DB> for Custom<'q, DB> {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::database::HasArguments<'q>>::ArgumentBuffer,
+    ) -> sqlx::encode::IsNull {
+        self.0.encode(buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        Some(self.0.type_info())
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+impl<'q, DB: Database> sqlx::Type<DB> for Custom<'q, DB>
+where
+    str: sqlx::Type<DB>,
+{
+    // never consulted, `produces` always reports the actual type.
+    fn type_info() -> DB::TypeInfo {
+        <str as sqlx::Type<DB>>::type_info()
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+pub(super) fn unsupported_custom<DB>(val: &dyn crate::value::CustomValue) -> sqlx::Error {
+    sqlx::Error::Io(std::io::Error::other(format!(
+        "no encoder for {:?} is provided for {}",
+        val,
+        std::any::type_name::<DB>(),
+    )))
+}
+
 #[cfg(any(feature = "postgres", feature = "sqlite"))]
 fn unsupported<DB, T>() -> sqlx::Error {
     sqlx::Error::Io(std::io::Error::new(
@@ -158,17 +231,65 @@ impl Bind for sqlx::Postgres {
             Value::Null(crate::value::Null::Text(..)) => Ok(query.bind(None::<&'q str>)),
             Value::Null(crate::value::Null::Bytes(..)) => Ok(query.bind(None::<&'q str>)),
             #[cfg(feature = "use-chrono")]
+            Value::NaiveDate(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
             Value::DateTime(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
+            Value::FixedDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveDate(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDate>))
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveTime(..)) => {
+                Ok(query.bind(None::<chrono::NaiveTime>))
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveDateTime(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDateTime>))
+            }
+            #[cfg(feature = "use-chrono")]
             Value::Null(crate::value::Null::DateTime(..)) => {
                 Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
             }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::FixedDateTime(..)) => {
+                Ok(query.bind(None::<chrono::DateTime<chrono::FixedOffset>>))
+            }
+            #[cfg(feature = "use-time")]
+            Value::Date(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::Time(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::PrimitiveDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::OffsetDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::Date(..)) => Ok(query.bind(None::<time::Date>)),
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::Time(..)) => Ok(query.bind(None::<time::Time>)),
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::PrimitiveDateTime(..)) => {
+                Ok(query.bind(None::<time::PrimitiveDateTime>))
+            }
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::OffsetDateTime(..)) => {
+                Ok(query.bind(None::<time::OffsetDateTime>))
+            }
             #[cfg(feature = "use-decimal")]
             Value::Numeric(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-decimal")]
             Value::Null(crate::value::Null::Numeric(..)) => {
                 Ok(query.bind(None::<rust_decimal::Decimal>))
             }
+            Value::Custom(val) => match val.postgres() {
+                Some(encoder) => Ok(query.bind(Custom(encoder))),
+                None => Err(unsupported_custom::<sqlx::Postgres>(val)),
+            },
         }
     }
 }
@@ -204,17 +325,65 @@ impl Bind for sqlx::MySql {
             Value::Null(crate::value::Null::Text(..)) => Ok(query.bind(None::<&'q str>)),
             Value::Null(crate::value::Null::Bytes(..)) => Ok(query.bind(None::<&'q str>)),
             #[cfg(feature = "use-chrono")]
+            Value::NaiveDate(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
             Value::DateTime(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
+            Value::FixedDateTime(val) => Ok(query.bind(val.with_timezone(&chrono::Utc))),
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveDate(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDate>))
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveTime(..)) => {
+                Ok(query.bind(None::<chrono::NaiveTime>))
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveDateTime(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDateTime>))
+            }
+            #[cfg(feature = "use-chrono")]
             Value::Null(crate::value::Null::DateTime(..)) => {
                 Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
             }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::FixedDateTime(..)) => {
+                Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
+            }
+            #[cfg(feature = "use-time")]
+            Value::Date(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::Time(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::PrimitiveDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::OffsetDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::Date(..)) => Ok(query.bind(None::<time::Date>)),
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::Time(..)) => Ok(query.bind(None::<time::Time>)),
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::PrimitiveDateTime(..)) => {
+                Ok(query.bind(None::<time::PrimitiveDateTime>))
+            }
+            #[cfg(feature = "use-time")]
+            Value::Null(crate::value::Null::OffsetDateTime(..)) => {
+                Ok(query.bind(None::<time::OffsetDateTime>))
+            }
             #[cfg(feature = "use-decimal")]
             Value::Numeric(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-decimal")]
             Value::Null(crate::value::Null::Numeric(..)) => {
                 Ok(query.bind(None::<rust_decimal::Decimal>))
             }
+            Value::Custom(val) => match val.mysql() {
+                Some(encoder) => Ok(query.bind(Custom(encoder))),
+                None => Err(unsupported_custom::<sqlx::MySql>(val)),
+            },
         }
     }
 }
@@ -250,15 +419,60 @@ impl Bind for sqlx::Sqlite {
             Value::Null(crate::value::Null::Text(..)) => Ok(query.bind(None::<&'q str>)),
             Value::Null(crate::value::Null::Bytes(..)) => Ok(query.bind(None::<&'q str>)),
             #[cfg(feature = "use-chrono")]
+            Value::NaiveDate(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
             Value::DateTime(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
+            Value::FixedDateTime(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveDate(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDate>))
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveTime(..)) => {
+                Ok(query.bind(None::<chrono::NaiveTime>))
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::NaiveDateTime(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDateTime>))
+            }
+            #[cfg(feature = "use-chrono")]
             Value::Null(crate::value::Null::DateTime(..)) => {
                 Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
             }
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::FixedDateTime(..)) => {
+                Ok(query.bind(None::<chrono::DateTime<chrono::FixedOffset>>))
+            }
+            #[cfg(feature = "use-time")]
+            Value::Date(..) | Value::Null(crate::value::Null::Date(..)) => {
+                Err(unsupported::<sqlx::Sqlite, time::Date>())
+            }
+            #[cfg(feature = "use-time")]
+            Value::Time(..) | Value::Null(crate::value::Null::Time(..)) => {
+                Err(unsupported::<sqlx::Sqlite, time::Time>())
+            }
+            #[cfg(feature = "use-time")]
+            Value::PrimitiveDateTime(..)
+            | Value::Null(crate::value::Null::PrimitiveDateTime(..)) => {
+                Err(unsupported::<sqlx::Sqlite, time::PrimitiveDateTime>())
+            }
+            #[cfg(feature = "use-time")]
+            Value::OffsetDateTime(..) | Value::Null(crate::value::Null::OffsetDateTime(..)) => {
+                Err(unsupported::<sqlx::Sqlite, time::OffsetDateTime>())
+            }
             #[cfg(feature = "use-decimal")]
             Value::Numeric(..) => Err(unsupported::<sqlx::Sqlite, u64>()),
             #[cfg(feature = "use-decimal")]
             Value::Null(crate::value::Null::Numeric(..)) => Err(unsupported::<sqlx::Sqlite, u64>()),
+            Value::Custom(val) => match val.sqlite() {
+                Some(encoder) => Ok(query.bind(Custom(encoder))),
+                None => Err(unsupported_custom::<sqlx::Sqlite>(val)),
+            },
         }
     }
 }
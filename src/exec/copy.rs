@@ -0,0 +1,438 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::stream::{BoxStream, Stream};
+use sqlx::postgres::{PgArgumentBuffer, PgConnection, PgCopyIn};
+use sqlx::{Encode, Postgres};
+
+use crate::build::{Dialect, ToSql};
+use crate::expr::Expr;
+use crate::item::{Ident, Row, TableRef};
+use crate::stmt::Stmt;
+use crate::value::{Null, Value};
+
+/// Size of the buffered rows sent in a single `CopyData` message.
+const CHUNK: usize = 64 * 1024;
+
+/// Data format of `COPY FROM STDIN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// Tab separated text, values parsed by postgres from their text form.
+    Text,
+    /// Binary format, values must match the column types exactly.
+    Binary,
+}
+
+impl std::default::Default for CopyFormat {
+    #[inline]
+    fn default() -> Self {
+        CopyFormat::Text
+    }
+}
+
+/// `COPY table (columns) FROM STDIN` bulk loader.
+///
+/// Rows are either [`Row`] or anything convertible into one, and may only
+/// contain literal values. Structs deriving `Schema` have no such conversion,
+/// map them into tuples first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CopyIn<'a> {
+    table: TableRef<'a>,
+    columns: Vec<Ident<'a>>,
+    format: CopyFormat,
+}
+
+/// Start a `COPY FROM STDIN` into `columns` of `table`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(conn: &mut sqlx::PgConnection) -> Result<(), sqlx::Error> {
+/// let rows = (0..1_000_000).map(|id| (id, id % 100));
+/// let count = xql::exec::copy_in("book", ["id", "stock"])
+///     .rows(rows, conn)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn copy_in<'a, T, C>(table: T, columns: C) -> CopyIn<'a>
+where
+    T: Into<TableRef<'a>>,
+    C: IntoIterator,
+    C::Item: Into<Ident<'a>>,
+{
+    CopyIn {
+        table: table.into(),
+        columns: columns.into_iter().map(Into::into).collect(),
+        format: CopyFormat::Text,
+    }
+}
+
+impl<'a> CopyIn<'a> {
+    /// Set the data format, [`CopyFormat::Text`] by default.
+    pub fn format(mut self, format: CopyFormat) -> CopyIn<'a> {
+        self.format = format;
+        self
+    }
+
+    /// Shorthand for `format(CopyFormat::Binary)`.
+    pub fn binary(self) -> CopyIn<'a> {
+        self.format(CopyFormat::Binary)
+    }
+
+    /// The `COPY` statement issued.
+    pub fn statement(&self) -> String {
+        let mut sql = String::from("COPY ");
+        let mut args = Vec::new();
+        // identifiers never fail to render.
+        let _ = self.table.build::<Postgres>(&mut sql, &mut args);
+        if !self.columns.is_empty() {
+            sql.push_str(" (");
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(", ");
+                }
+                Postgres::quote_ident(column.0, &mut sql);
+            }
+            sql.push(')');
+        }
+        sql.push_str(" FROM STDIN");
+        if self.format == CopyFormat::Binary {
+            sql.push_str(" WITH (FORMAT binary)");
+        }
+        sql
+    }
+
+    /// Send every row of `rows`, returning the number of rows copied.
+    pub async fn rows<I>(self, rows: I, conn: &mut PgConnection) -> Result<u64, sqlx::Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<Row<'a>>,
+    {
+        let mut copy = conn.copy_in_raw(self.statement().as_str()).await?;
+        let mut buff = self.header();
+        for row in rows {
+            if let Err(err) = self.encode(row.into(), &mut buff) {
+                return abort(copy, err).await;
+            }
+            if buff.len() >= CHUNK {
+                copy.send(std::mem::take(&mut buff)).await?;
+            }
+        }
+        self.finish(copy, buff).await
+    }
+
+    /// Send every row of `rows`, returning the number of rows copied.
+    pub async fn stream<S>(self, mut rows: S, conn: &mut PgConnection) -> Result<u64, sqlx::Error>
+    where
+        S: Stream + Unpin,
+        S::Item: Into<Row<'a>>,
+    {
+        let mut copy = conn.copy_in_raw(self.statement().as_str()).await?;
+        let mut buff = self.header();
+        while let Some(row) = Next(&mut rows).await {
+            if let Err(err) = self.encode(row.into(), &mut buff) {
+                return abort(copy, err).await;
+            }
+            if buff.len() >= CHUNK {
+                copy.send(std::mem::take(&mut buff)).await?;
+            }
+        }
+        self.finish(copy, buff).await
+    }
+
+    fn header(&self) -> Vec<u8> {
+        let mut buff = Vec::with_capacity(CHUNK);
+        if self.format == CopyFormat::Binary {
+            buff.extend_from_slice(b"PGCOPY\n\xff\r\n\0");
+            buff.extend_from_slice(&0i32.to_be_bytes()); // flags
+            buff.extend_from_slice(&0i32.to_be_bytes()); // header extension
+        }
+        buff
+    }
+
+    fn encode(&self, row: Row<'a>, buff: &mut Vec<u8>) -> Result<(), sqlx::Error> {
+        match self.format {
+            CopyFormat::Text => encode_text(row, buff),
+            CopyFormat::Binary => encode_binary(row, buff),
+        }
+    }
+
+    async fn finish(
+        &self,
+        mut copy: PgCopyIn<&mut PgConnection>,
+        mut buff: Vec<u8>,
+    ) -> Result<u64, sqlx::Error> {
+        if self.format == CopyFormat::Binary {
+            buff.extend_from_slice(&(-1i16).to_be_bytes());
+        }
+        if !buff.is_empty() {
+            copy.send(buff).await?;
+        }
+        copy.finish().await
+    }
+}
+
+/// Stream the result of `stmt` as CSV through `COPY (stmt) TO STDOUT`.
+///
+/// `COPY` does not accept parameters, values of `stmt` are inlined.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(conn: &mut sqlx::PgConnection) -> Result<(), sqlx::Error> {
+/// let query = xql::select(["id", "title"]).from("book");
+/// let mut csv = xql::exec::copy_out(query, conn).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn copy_out<'c, 'v, S>(
+    stmt: S,
+    conn: &'c mut PgConnection,
+) -> Result<BoxStream<'c, Result<bytes::Bytes, sqlx::Error>>, sqlx::Error>
+where
+    S: Into<Stmt<'v>>,
+{
+    let mut sql = String::from("COPY (");
+    let mut args = Vec::new();
+    stmt.into()
+        .build::<<Postgres as Dialect>::Inline>(&mut sql, &mut args)
+        .map_err(super::error)?;
+    sql.push_str(") TO STDOUT WITH (FORMAT csv)");
+    conn.copy_out_raw(sql.as_str()).await
+}
+
+async fn abort(copy: PgCopyIn<&mut PgConnection>, err: sqlx::Error) -> Result<u64, sqlx::Error> {
+    copy.abort(err.to_string()).await?;
+    Err(err)
+}
+
+/// Poll the next item of a stream.
+struct Next<'s, S>(&'s mut S);
+
+impl<S: Stream + Unpin> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.0).poll_next(cx)
+    }
+}
+
+fn encode<'q, T: Encode<'q, Postgres>>(val: T, buf: &mut PgArgumentBuffer) -> sqlx::encode::IsNull {
+    val.encode_by_ref(buf)
+}
+
+fn value(expr: Expr<'_>) -> Result<Value<'_>, sqlx::Error> {
+    match expr {
+        Expr::Literal(val) => Ok(val),
+        _ => Err(super::error(crate::error::Error::Invalid {
+            reason: "COPY of an expression, only values can be copied",
+        })),
+    }
+}
+
+fn unsupported(feature: &'static str) -> sqlx::Error {
+    super::error(crate::build::unsupported::<Postgres>(feature))
+}
+
+/// Encode `row` in the text format: tab separated columns with `\N` for
+/// nulls, ended by a newline.
+fn encode_text(row: Row<'_>, buff: &mut Vec<u8>) -> Result<(), sqlx::Error> {
+    #[cfg(feature = "use-time")]
+    use crate::build::Fraction;
+    use std::io::Write;
+
+    for (i, expr) in row.0.into_iter().enumerate() {
+        if i > 0 {
+            buff.push(b'\t');
+        }
+        match value(expr)? {
+            Value::Null(..) => buff.extend_from_slice(b"\\N"),
+            Value::Bool(val) => buff.push(if val { b't' } else { b'f' }),
+            Value::TinyInt(val) => write!(buff, "{}", val)?,
+            Value::SmallInt(val) => write!(buff, "{}", val)?,
+            Value::Int(val) => write!(buff, "{}", val)?,
+            Value::BigInt(val) => write!(buff, "{}", val)?,
+            Value::TinyUInt(val) => write!(buff, "{}", val)?,
+            Value::SmallUInt(val) => write!(buff, "{}", val)?,
+            Value::UInt(val) => write!(buff, "{}", val)?,
+            Value::BigUInt(val) => write!(buff, "{}", val)?,
+            Value::Text(val) => escape(val, buff),
+            Value::Bytes(val) => {
+                buff.extend_from_slice(b"\\\\x");
+                for byte in val {
+                    write!(buff, "{:02x}", byte)?;
+                }
+            }
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDate(val) => write!(buff, "{}", val.format("%F"))?,
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveTime(val) => write!(buff, "{}", val.format("%T%.f"))?,
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDateTime(val) => write!(buff, "{}", val.format("%F %T%.f"))?,
+            #[cfg(feature = "use-chrono")]
+            Value::DateTime(val) => write!(buff, "{}", val.format("%F %T%.f%:z"))?,
+            #[cfg(feature = "use-chrono")]
+            Value::FixedDateTime(val) => write!(buff, "{}", val.format("%F %T%.f%:z"))?,
+            #[cfg(feature = "use-time")]
+            Value::Date(val) => write!(buff, "{}", val.format("%F"))?,
+            #[cfg(feature = "use-time")]
+            Value::Time(val) => write!(buff, "{}{}", val.format("%T"), Fraction(val.nanosecond()))?,
+            #[cfg(feature = "use-time")]
+            Value::PrimitiveDateTime(val) => write!(
+                buff,
+                "{}{}",
+                val.format("%F %T"),
+                Fraction(val.nanosecond())
+            )?,
+            #[cfg(feature = "use-time")]
+            Value::OffsetDateTime(val) => write!(
+                buff,
+                "{}{} {}",
+                val.format("%F %T"),
+                Fraction(val.nanosecond()),
+                val.format("%z")
+            )?,
+            #[cfg(feature = "use-decimal")]
+            Value::Numeric(val) => write!(buff, "{}", val)?,
+            Value::Custom(..) => return Err(unsupported("text COPY of custom values")),
+        }
+    }
+    buff.push(b'\n');
+    Ok(())
+}
+
+fn escape(val: &str, buff: &mut Vec<u8>) {
+    for &byte in val.as_bytes() {
+        match byte {
+            b'\\' => buff.extend_from_slice(b"\\\\"),
+            b'\t' => buff.extend_from_slice(b"\\t"),
+            b'\n' => buff.extend_from_slice(b"\\n"),
+            b'\r' => buff.extend_from_slice(b"\\r"),
+            byte => buff.push(byte),
+        }
+    }
+}
+
+/// Encode `row` in the binary format: the number of columns followed by each
+/// column length and data, a length of `-1` being null.
+fn encode_binary(row: Row<'_>, buff: &mut Vec<u8>) -> Result<(), sqlx::Error> {
+    buff.extend_from_slice(&(row.0.len() as i16).to_be_bytes());
+    for expr in row.0 {
+        let mut field = PgArgumentBuffer::default();
+        let null = match value(expr)? {
+            Value::Null(Null::TinyUInt(..)) | Value::TinyUInt(..) => {
+                return Err(unsupported("binary COPY of u8"))
+            }
+            Value::Null(Null::SmallUInt(..)) | Value::SmallUInt(..) => {
+                return Err(unsupported("binary COPY of u16"))
+            }
+            Value::Null(Null::UInt(..)) | Value::UInt(..) => {
+                return Err(unsupported("binary COPY of u32"))
+            }
+            Value::Null(Null::BigUInt(..)) | Value::BigUInt(..) => {
+                return Err(unsupported("binary COPY of u64"))
+            }
+            Value::Null(..) => sqlx::encode::IsNull::Yes,
+            Value::Bool(val) => encode(val, &mut field),
+            // postgres has no one byte integer besides `"char"`.
+            Value::TinyInt(val) => encode(i16::from(val), &mut field),
+            Value::SmallInt(val) => encode(val, &mut field),
+            Value::Int(val) => encode(val, &mut field),
+            Value::BigInt(val) => encode(val, &mut field),
+            Value::Text(val) => encode(val, &mut field),
+            Value::Bytes(val) => encode(val, &mut field),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDate(val) => encode(val, &mut field),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveTime(val) => encode(val, &mut field),
+            #[cfg(feature = "use-chrono")]
+            Value::NaiveDateTime(val) => encode(val, &mut field),
+            #[cfg(feature = "use-chrono")]
+            Value::DateTime(val) => encode(val, &mut field),
+            #[cfg(feature = "use-chrono")]
+            Value::FixedDateTime(val) => encode(val, &mut field),
+            #[cfg(feature = "use-time")]
+            Value::Date(val) => encode(val, &mut field),
+            #[cfg(feature = "use-time")]
+            Value::Time(val) => encode(val, &mut field),
+            #[cfg(feature = "use-time")]
+            Value::PrimitiveDateTime(val) => encode(val, &mut field),
+            #[cfg(feature = "use-time")]
+            Value::OffsetDateTime(val) => encode(val, &mut field),
+            #[cfg(feature = "use-decimal")]
+            Value::Numeric(val) => encode(val, &mut field),
+            Value::Custom(val) => match val.postgres() {
+                Some(encoder) => encoder.encode(&mut field),
+                None => return Err(super::bind::unsupported_custom::<Postgres>(val)),
+            },
+        };
+        match null {
+            sqlx::encode::IsNull::Yes => buff.extend_from_slice(&(-1i32).to_be_bytes()),
+            sqlx::encode::IsNull::No => {
+                buff.extend_from_slice(&(field.len() as i32).to_be_bytes());
+                buff.extend_from_slice(&field);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    let copy = copy_in(("public", "book"), ["id", "title", "cover"]);
+    assert_eq!(
+        copy.statement(),
+        "COPY \"public\".\"book\" (\"id\", \"title\", \"cover\") FROM STDIN"
+    );
+    assert_eq!(
+        copy.clone().binary().statement(),
+        "COPY \"public\".\"book\" (\"id\", \"title\", \"cover\") FROM STDIN WITH (FORMAT binary)"
+    );
+
+    let title = "a\\b\tc\nd".to_string();
+    let cover = vec![0xde, 0xad];
+    let null: Option<&String> = None;
+
+    let mut buff = Vec::new();
+    encode_text((1, &title, &cover).into(), &mut buff).unwrap();
+    encode_text((1, null, &cover).into(), &mut buff).unwrap();
+    assert_eq!(buff, b"1\ta\\\\b\\tc\\nd\t\\\\xdead\n1\t\\N\t\\\\xdead\n");
+
+    let mut buff = Vec::new();
+    encode_binary((1, null, &cover).into(), &mut buff).unwrap();
+    assert_eq!(
+        buff,
+        [0, 3, 0, 0, 0, 4, 0, 0, 0, 1, 255, 255, 255, 255, 0, 0, 0, 2, 0xde, 0xad]
+    );
+
+    let mut buff = Vec::new();
+    encode_binary((7i8,).into(), &mut buff).unwrap();
+    assert_eq!(buff, [0, 1, 0, 0, 0, 2, 0, 7]);
+
+    assert_eq!(
+        encode_binary((7u8,).into(), &mut Vec::new())
+            .unwrap_err()
+            .to_string(),
+        "error communicating with database: binary COPY of u8 is not supported by postgres"
+    );
+
+    #[cfg(feature = "use-time")]
+    {
+        let time = time::Time::try_from_hms_nano(12, 30, 0, 250_000_000).unwrap();
+        let datetime = time::Date::try_from_ymd(1965, 8, 1)
+            .unwrap()
+            .with_time(time);
+        let mut buff = Vec::new();
+        encode_text((time, datetime, datetime.assume_utc()).into(), &mut buff).unwrap();
+        assert_eq!(
+            buff,
+            b"12:30:00.250\t1965-08-01 12:30:00.250\t1965-08-01 12:30:00.250 +0000\n"
+        );
+    }
+
+    assert!(encode_text(("id",).into(), &mut Vec::new()).is_err());
+    assert!(encode_binary((1u32,).into(), &mut Vec::new()).is_err());
+}
@@ -0,0 +1,333 @@
+use sqlx::Executor;
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use serde_json::Value as JsonValue;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use sqlx::types::Json;
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+use sqlx::Row;
+
+use crate::build::{Dialect, Syntax, ToSql};
+use crate::stmt::explain::Explain;
+use crate::stmt::Stmt;
+
+use super::Backend;
+
+/// A node of a query plan, common to every backend.
+///
+/// Node names are the backend's own: `Index Scan` on postgres, the access
+/// type such as `ref` or `ALL` on mysql, `SCAN` or `SEARCH` on sqlite.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Plan {
+    pub node: String,
+    /// Table read by this node.
+    pub relation: Option<String>,
+    /// Index read by this node.
+    pub index: Option<String>,
+    /// Estimated number of rows.
+    pub rows: Option<f64>,
+    /// Actual number of rows, when analyzed on postgres.
+    pub actual_rows: Option<f64>,
+    /// Estimated total cost.
+    pub cost: Option<f64>,
+    /// Raw line of sqlite query plan.
+    pub detail: Option<String>,
+    pub children: Vec<Plan>,
+}
+
+impl Plan {
+    /// Iterate over this node and its descendants, depth first.
+    pub fn nodes(&self) -> impl Iterator<Item = &Plan> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Whether any node reads index `name`.
+    pub fn uses_index(&self, name: &str) -> bool {
+        self.nodes().any(|node| node.index.as_deref() == Some(name))
+    }
+}
+
+/// Backend able to parse its own `EXPLAIN` output into a [`Plan`].
+pub trait Explainer: Backend + Dialect {
+    fn plan(rows: Vec<Self::Row>) -> Result<Plan, sqlx::Error>;
+}
+
+/// Run `stmt` with its bind parameters and parse the resulting plan.
+///
+/// `FORMAT JSON` is always requested on postgres and mysql. Mysql has no
+/// `EXPLAIN ANALYZE` in that format, `ANALYZE` is rejected there. Beware that
+/// `ANALYZE` actually executes the statement.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(pool: &sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+/// use xql::ops::eq;
+///
+/// let query = xql::select(["id"]).from("book").filter(eq("title", 1));
+/// let plan = xql::exec::explain(xql::explain(query), pool).await?;
+/// assert!(plan.uses_index("book_title"));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn explain<'c, 'v, E>(stmt: Explain<'v>, executor: E) -> Result<Plan, sqlx::Error>
+where
+    E: Executor<'c>,
+    E::Database: Explainer,
+{
+    let stmt = parsable::<E::Database>(stmt).map_err(super::error)?;
+    let (sql, args) = Stmt::from(stmt)
+        .to_sql::<E::Database>()
+        .map_err(super::error)?;
+    let rows = E::Database::fetch_all(executor, sql, args).await?;
+    E::Database::plan(rows)
+}
+
+/// Request the output format parsed by [`Explainer::plan`] of `D`.
+fn parsable<D: Dialect>(stmt: Explain<'_>) -> crate::error::Result<Explain<'_>> {
+    match D::SYNTAX {
+        Syntax::Sqlite => Ok(stmt),
+        Syntax::MySql if stmt.analyze => Err(crate::build::unsupported::<D>(
+            "query plan of EXPLAIN ANALYZE",
+        )),
+        _ => Ok(stmt.format_json()),
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn malformed(what: &str) -> sqlx::Error {
+    sqlx::Error::Protocol(format!("malformed {} query plan", what))
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn number(val: &JsonValue) -> Option<f64> {
+    match val {
+        JsonValue::String(val) => val.parse().ok(),
+        val => val.as_f64(),
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn string(val: &JsonValue) -> Option<String> {
+    val.as_str().map(ToString::to_string)
+}
+
+/// Convert a plan node of postgres `EXPLAIN (FORMAT JSON)`.
+#[cfg(feature = "postgres")]
+fn postgres(node: &JsonValue) -> Plan {
+    Plan {
+        node: node["Node Type"].as_str().unwrap_or_default().to_string(),
+        relation: string(&node["Relation Name"]),
+        index: string(&node["Index Name"]),
+        rows: number(&node["Plan Rows"]),
+        actual_rows: number(&node["Actual Rows"]),
+        cost: number(&node["Total Cost"]),
+        detail: None,
+        children: node["Plans"]
+            .as_array()
+            .map(|plans| plans.iter().map(postgres).collect())
+            .unwrap_or_default(),
+    }
+}
+
+/// Convert an operation of mysql `EXPLAIN FORMAT=JSON`, tables becoming
+/// nodes named after their access type.
+#[cfg(feature = "mysql")]
+fn mysql(name: &str, node: &JsonValue) -> Plan {
+    let mut plan = Plan {
+        node: name.to_string(),
+        cost: number(&node["cost_info"]["query_cost"])
+            .or_else(|| number(&node["cost_info"]["prefix_cost"])),
+        ..Default::default()
+    };
+    if name == "table" {
+        if let Some(access) = node["access_type"].as_str() {
+            plan.node = access.to_string();
+        }
+        plan.relation = string(&node["table_name"]);
+        plan.index = string(&node["key"]);
+        plan.rows = number(&node["rows_examined_per_scan"]);
+    }
+    for (key, child) in node.as_object().into_iter().flatten() {
+        match child {
+            JsonValue::Object(..) if key != "cost_info" => plan.children.push(mysql(key, child)),
+            JsonValue::Array(items) if items.iter().any(JsonValue::is_object) => {
+                let mut list = Plan {
+                    node: key.to_string(),
+                    ..Default::default()
+                };
+                for item in items {
+                    match item.as_object() {
+                        Some(obj) if obj.len() == 1 => {
+                            let (key, child) = obj.iter().next().unwrap();
+                            list.children.push(mysql(key, child));
+                        }
+                        Some(..) => list.children.push(mysql(key, item)),
+                        None => {}
+                    }
+                }
+                plan.children.push(list);
+            }
+            _ => {}
+        }
+    }
+    plan
+}
+
+/// Convert rows `(id, parent, detail)` of sqlite `EXPLAIN QUERY PLAN`.
+#[cfg(feature = "sqlite")]
+fn sqlite(rows: &[(i64, i64, String)], parent: i64) -> Vec<Plan> {
+    rows.iter()
+        .filter(|row| row.1 == parent)
+        .map(|(id, _, detail)| {
+            let mut words = detail.split(' ');
+            let node = words.next().unwrap_or_default();
+            let mut plan = Plan {
+                node: detail.clone(),
+                detail: Some(detail.clone()),
+                children: sqlite(rows, *id),
+                ..Default::default()
+            };
+            if node == "SCAN" || node == "SEARCH" {
+                plan.node = node.to_string();
+                plan.relation = words.find(|word| *word != "TABLE").map(ToString::to_string);
+                plan.index = ["USING INDEX ", "USING COVERING INDEX "]
+                    .iter()
+                    .find_map(|using| detail.split_once(using))
+                    .and_then(|(_, rest)| rest.split(' ').next())
+                    .map(ToString::to_string);
+            }
+            plan
+        })
+        .collect()
+}
+
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+impl Explainer for sqlx::Postgres {
+    fn plan(rows: Vec<Self::Row>) -> Result<Plan, sqlx::Error> {
+        let row = rows.first().ok_or_else(|| malformed("postgres"))?;
+        let Json(json) = row.try_get::<Json<JsonValue>, _>(0)?;
+        match &json[0]["Plan"] {
+            JsonValue::Object(..) => Ok(postgres(&json[0]["Plan"])),
+            _ => Err(malformed("postgres")),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+impl Explainer for sqlx::MySql {
+    fn plan(rows: Vec<Self::Row>) -> Result<Plan, sqlx::Error> {
+        let row = rows.first().ok_or_else(|| malformed("mysql"))?;
+        let Json(json) = row.try_get::<Json<JsonValue>, _>(0)?;
+        match &json["query_block"] {
+            JsonValue::Object(..) => Ok(mysql("query_block", &json["query_block"])),
+            _ => Err(malformed("mysql")),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+impl Explainer for sqlx::Sqlite {
+    fn plan(rows: Vec<Self::Row>) -> Result<Plan, sqlx::Error> {
+        let rows = rows
+            .iter()
+            .map(|row| Ok((row.try_get(0)?, row.try_get(1)?, row.try_get(3)?)))
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+        Ok(Plan {
+            node: "QUERY PLAN".to_string(),
+            children: sqlite(&rows, 0),
+            ..Default::default()
+        })
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql"))]
+    let parse = |json: &str| json.parse::<JsonValue>().unwrap();
+
+    #[cfg(feature = "postgres")]
+    {
+        let query = crate::stmt::explain(crate::stmt::select(["id"]).from("book"));
+        assert!(parsable::<sqlx::Postgres>(query.analyze()).unwrap().json);
+
+        let node = parse(
+            r#"{"Node Type": "Nested Loop", "Total Cost": 16.5, "Plan Rows": 10, "Actual Rows": 8,
+                "Plans": [
+                    {"Node Type": "Seq Scan", "Relation Name": "author", "Plan Rows": 10},
+                    {"Node Type": "Index Scan", "Relation Name": "book", "Index Name": "book_author_idx"}
+                ]}"#,
+        );
+        let plan = postgres(&node);
+        assert_eq!(plan.node, "Nested Loop");
+        assert_eq!(
+            (plan.cost, plan.rows, plan.actual_rows),
+            (Some(16.5), Some(10.0), Some(8.0))
+        );
+        assert_eq!(
+            plan.nodes()
+                .map(|node| node.node.as_str())
+                .collect::<Vec<_>>(),
+            ["Nested Loop", "Seq Scan", "Index Scan"]
+        );
+        assert!(plan.uses_index("book_author_idx"));
+        assert!(!plan.uses_index("book_pkey"));
+    }
+
+    #[cfg(feature = "mysql")]
+    {
+        let query = crate::stmt::explain(crate::stmt::select(["id"]).from("book"));
+        assert!(parsable::<sqlx::MySql>(query.clone()).unwrap().json);
+        assert!(matches!(
+            parsable::<sqlx::MySql>(query.analyze()),
+            Err(crate::error::Error::Unsupported { .. })
+        ));
+
+        let node = parse(
+            r#"{"select_id": 1, "cost_info": {"query_cost": "2.40"},
+                "nested_loop": [
+                    {"table": {"table_name": "author", "access_type": "ALL", "rows_examined_per_scan": 3}},
+                    {"table": {"table_name": "book", "access_type": "ref", "key": "book_author_idx",
+                               "used_key_parts": ["author_id"], "cost_info": {"prefix_cost": "2.40"}}}
+                ]}"#,
+        );
+        let plan = mysql("query_block", &node);
+        assert_eq!(plan.cost, Some(2.4));
+        let nodes = plan.nodes().collect::<Vec<_>>();
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes[2].node, "ALL");
+        assert_eq!(nodes[2].relation.as_deref(), Some("author"));
+        assert_eq!(nodes[2].rows, Some(3.0));
+        assert_eq!(nodes[3].node, "ref");
+        assert!(plan.uses_index("book_author_idx"));
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        let rows = [
+            (2, 0, "SCAN author".to_string()),
+            (
+                5,
+                0,
+                "SEARCH book USING INDEX book_author_idx (author_id=?)".to_string(),
+            ),
+            (9, 0, "USE TEMP B-TREE FOR ORDER BY".to_string()),
+        ];
+        let plans = sqlite(&rows, 0);
+        assert_eq!(plans.len(), 3);
+        assert_eq!(plans[0].relation.as_deref(), Some("author"));
+        assert_eq!(plans[1].node, "SEARCH");
+        assert_eq!(plans[1].index.as_deref(), Some("book_author_idx"));
+        assert_eq!(plans[2].node, "USE TEMP B-TREE FOR ORDER BY");
+    }
+}
@@ -16,6 +16,28 @@ use crate::{build::Dialect, build::ToSql, exec::bind::Bind, stmt::Stmt, value::V
 
 pub mod bind;
 
+#[cfg(feature = "postgres")]
+mod copy;
+
+#[cfg(feature = "explain")]
+mod explain;
+
+#[cfg(feature = "explain")]
+#[cfg_attr(docsrs, doc(cfg(feature = "explain")))]
+pub use explain::{explain, Explainer, Plan};
+
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+pub use copy::{copy_in, copy_out, CopyFormat, CopyIn};
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+use crate::build::Syntax;
+
+#[cfg(feature = "sqlx")]
+fn error(err: crate::error::Error) -> sqlx::Error {
+    sqlx::Error::Io(std::io::Error::other(err))
+}
+
 #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
 fn quote(buff: &mut String, val: &str, q: char) {
     buff.push(q);
@@ -30,6 +52,10 @@ fn quote(buff: &mut String, val: &str, q: char) {
 
 #[cfg(feature = "postgres")]
 impl Dialect for Postgres {
+    const SYNTAX: Syntax = Syntax::Postgres;
+
+    type Inline = crate::build::Inline<Self>;
+
     fn quote_literal(val: &str, buff: &mut String) {
         quote(buff, val, '\'')
     }
@@ -47,8 +73,13 @@ impl Dialect for Postgres {
 
 #[cfg(feature = "mysql")]
 impl Dialect for MySql {
+    const SYNTAX: Syntax = Syntax::MySql;
+
+    type Inline = crate::build::Inline<Self>;
+
     fn quote_literal(val: &str, buff: &mut String) {
-        quote(buff, val, '\'')
+        // backslash escapes in mysql strings, unless `NO_BACKSLASH_ESCAPES`.
+        quote(buff, &val.replace('\\', "\\\\"), '\'')
     }
 
     fn quote_ident(val: &str, buff: &mut String) {
@@ -63,6 +94,10 @@ impl Dialect for MySql {
 
 #[cfg(feature = "sqlite")]
 impl Dialect for Sqlite {
+    const SYNTAX: Syntax = Syntax::Sqlite;
+
+    type Inline = crate::build::Inline<Self>;
+
     fn quote_literal(val: &str, buff: &mut String) {
         quote(buff, val, '\'')
     }
@@ -348,7 +383,7 @@ where
     DB: Backend + Dialect,
     E: Executor<'c, Database = DB>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_one(executor, sql, args).await
 }
 
@@ -363,7 +398,7 @@ where
     DB: Backend + Dialect,
     E: Executor<'c, Database = DB>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_optional(executor, sql, args).await
 }
 
@@ -375,7 +410,7 @@ where
     DB: Backend + Dialect,
     E: Executor<'c, Database = DB>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_all(executor, sql, args).await
 }
 
@@ -388,7 +423,7 @@ where
     E::Database: Backend + Dialect,
     O: Send + Unpin + for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_one_as(executor, sql, args).await
 }
 
@@ -404,7 +439,7 @@ where
     E::Database: Backend + Dialect,
     O: Send + Unpin + for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_optional_as(executor, sql, args).await
 }
 
@@ -417,7 +452,7 @@ where
     E::Database: Backend + Dialect,
     O: Send + Unpin + for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_all_as(executor, sql, args).await
 }
 
@@ -431,7 +466,7 @@ where
     O: Send + Unpin,
     (O,): for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_one_scalar(executor, sql, args).await
 }
 
@@ -448,7 +483,7 @@ where
     O: Send + Unpin,
     (O,): for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_optional_scalar(executor, sql, args).await
 }
 
@@ -462,6 +497,6 @@ where
     O: Send + Unpin,
     (O,): for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = stmt.into().to_sql::<E::Database>().map_err(error)?;
     E::Database::fetch_all_scalar(executor, sql, args).await
 }
@@ -1,5 +1,7 @@
 use crate::item::ColumnRef;
 use crate::item::FuncCall;
+use crate::item::Ident;
+use crate::item::TableRef;
 use crate::stmt::data::Data;
 use crate::stmt::select::Select;
 use crate::stmt::values::Values;
@@ -16,6 +18,20 @@ pub enum Expr<'a> {
     Postfix(Box<Expr<'a>>, &'static str),
     Paren(Box<Expr<'a>>),
     SubQuery(Data<'a>),
+    /// Proposed value of a column inside `ON CONFLICT DO UPDATE`.
+    Excluded(Ident<'a>),
+    /// `DEFAULT` value of a column inside `INSERT` rows or `UPDATE` set.
+    Default,
+    /// `ROLLUP (..)` grouping element inside `GROUP BY`.
+    Rollup(Vec<Expr<'a>>),
+    /// `CUBE (..)` grouping element inside `GROUP BY`.
+    Cube(Vec<Expr<'a>>),
+    /// `GROUPING SETS ((..), ..)` grouping element inside `GROUP BY`.
+    GroupingSets(Vec<Vec<Expr<'a>>>),
+    /// `GROUPING(..)` function telling subtotal rows apart.
+    Grouping(Vec<Expr<'a>>),
+    /// `nextval`, `currval` or `setval` call on a postgres sequence.
+    Sequence(&'static str, TableRef<'a>, Option<Box<Expr<'a>>>),
 }
 
 crate::macros::gen_display!(Expr<'_>);
@@ -130,6 +146,65 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "use-chrono")]
+    fn chrono_fmt() {
+        let date = chrono::NaiveDate::from_ymd(1965, 8, 1);
+        let time = chrono::NaiveTime::from_hms(12, 30, 0);
+        let datetime = date.and_time(time);
+        let offset = chrono::FixedOffset::east(7 * 3600);
+        let fixed = chrono::DateTime::<chrono::FixedOffset>::from_utc(datetime, offset);
+        assert_eq!(Into::<Expr>::into(date).to_string(), "'1965-08-01'");
+        assert_eq!(Into::<Expr>::into(time).to_string(), "'12:30:00'");
+        assert_eq!(
+            Into::<Expr>::into(datetime).to_string(),
+            "'1965-08-01 12:30:00'"
+        );
+        assert_eq!(
+            Into::<Expr>::into(fixed).to_string(),
+            "'1965-08-01 19:30:00+07:00'"
+        );
+        assert_eq!(
+            Into::<Expr>::into(None::<chrono::NaiveDate>).to_string(),
+            "null"
+        );
+
+        let time = chrono::NaiveTime::from_hms_nano(12, 30, 0, 250_000_000);
+        assert_eq!(Into::<Expr>::into(time).to_string(), "'12:30:00.250'");
+    }
+
+    #[test]
+    #[cfg(feature = "use-time")]
+    fn time_fmt() {
+        let date = time::Date::try_from_ymd(1965, 8, 1).unwrap();
+        let time = time::Time::try_from_hms(12, 30, 0).unwrap();
+        let datetime = date.with_time(time);
+        assert_eq!(Into::<Expr>::into(date).to_string(), "'1965-08-01'");
+        assert_eq!(Into::<Expr>::into(time).to_string(), "'12:30:00'");
+        assert_eq!(
+            Into::<Expr>::into(datetime).to_string(),
+            "'1965-08-01 12:30:00'"
+        );
+        assert_eq!(
+            Into::<Expr>::into(datetime.assume_utc()).to_string(),
+            "'1965-08-01 12:30:00 +0000'"
+        );
+
+        let time = time::Time::try_from_hms_nano(12, 30, 0, 250_000_000).unwrap();
+        let datetime = date.with_time(time);
+        assert_eq!(Into::<Expr>::into(time).to_string(), "'12:30:00.250'");
+        assert_eq!(
+            Into::<Expr>::into(datetime).to_string(),
+            "'1965-08-01 12:30:00.250'"
+        );
+        assert_eq!(
+            Into::<Expr>::into(datetime.assume_utc()).to_string(),
+            "'1965-08-01 12:30:00.250 +0000'"
+        );
+        let time = time::Time::try_from_hms_nano(12, 30, 0, 1_500).unwrap();
+        assert_eq!(Into::<Expr>::into(time).to_string(), "'12:30:00.000001500'");
+    }
+
     #[test]
     fn option() {
         assert_eq!(Into::<Expr>::into(None::<i32>).to_string(), "null");
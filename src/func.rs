@@ -1,6 +1,7 @@
 use crate::expr::Expr;
 use crate::item::FuncCall;
 use crate::item::FuncRef;
+use crate::item::TableRef;
 
 /// Construct a function call expression.
 #[inline]
@@ -10,7 +11,11 @@ where
     A: Into<Expr<'a>>,
     I: IntoIterator<Item = A>,
 {
-    FuncCall(func.into(), args.into_iter().map(Into::into).collect())
+    FuncCall(
+        func.into(),
+        args.into_iter().map(Into::into).collect(),
+        None,
+    )
 }
 
 macro_rules! gen_funcs {
@@ -40,3 +45,48 @@ gen_funcs!(
     /// Construct a call to `max` aggregate function.
     max
 );
+
+/// Construct a `nextval('seq')` call advancing a postgres sequence.
+///
+/// # Examples
+///
+/// ```
+/// use xql::func::nextval;
+///
+/// assert_eq!(nextval("book_id_seq").to_string(), "nextval('book_id_seq')");
+/// ```
+#[inline]
+pub fn nextval<'a, S>(seq: S) -> Expr<'a>
+where
+    S: Into<TableRef<'a>>,
+{
+    Expr::Sequence("nextval", seq.into(), None)
+}
+
+/// Construct a `currval('seq')` call reading the last value of a postgres
+/// sequence in this session.
+#[inline]
+pub fn currval<'a, S>(seq: S) -> Expr<'a>
+where
+    S: Into<TableRef<'a>>,
+{
+    Expr::Sequence("currval", seq.into(), None)
+}
+
+/// Construct a `setval('seq', value)` call resetting a postgres sequence.
+///
+/// # Examples
+///
+/// ```
+/// use xql::func::setval;
+///
+/// assert_eq!(setval("book_id_seq", 100).to_string(), "setval('book_id_seq', 100)");
+/// ```
+#[inline]
+pub fn setval<'a, S, V>(seq: S, value: V) -> Expr<'a>
+where
+    S: Into<TableRef<'a>>,
+    V: Into<Expr<'a>>,
+{
+    Expr::Sequence("setval", seq.into(), Some(Box::new(value.into())))
+}
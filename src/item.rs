@@ -142,10 +142,154 @@ where
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct FuncCall<'a>(pub(crate) FuncRef<'a>, pub(crate) Vec<Expr<'a>>);
+pub struct FuncCall<'a>(
+    pub(crate) FuncRef<'a>,
+    pub(crate) Vec<Expr<'a>>,
+    pub(crate) Option<Over<'a>>,
+);
 
 crate::macros::gen_display!(FuncCall<'_>);
 
+impl<'a> FuncCall<'a> {
+    /// Turn into a window function call, `OVER w` for a named window or
+    /// `OVER (spec)` for a [`Window`] spec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::func;
+    /// use xql::item::Window;
+    ///
+    /// assert_eq!(
+    ///     func("rank", Vec::<xql::expr::Expr>::new()).over("w").to_string(),
+    ///     "rank() OVER w",
+    /// );
+    /// assert_eq!(
+    ///     xql::sum("price").over(Window::default().partition_by(["author"]).order_by(["year"])).to_string(),
+    ///     "sum(price) OVER (PARTITION BY author ORDER BY year)",
+    /// );
+    /// ```
+    pub fn over<O>(mut self, window: O) -> FuncCall<'a>
+    where
+        O: Into<Over<'a>>,
+    {
+        self.2 = Some(window.into());
+        self
+    }
+}
+
+/// Window of a window function call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Over<'a> {
+    /// `OVER name`, a window defined in `WINDOW` clause.
+    Name(Ident<'a>),
+    /// `OVER (spec)`
+    Spec(Window<'a>),
+}
+
+crate::macros::gen_display!(Over<'_>);
+
+impl<'a> std::convert::From<&'a str> for Over<'a> {
+    #[inline]
+    fn from(val: &'a str) -> Self {
+        Over::Name(val.into())
+    }
+}
+
+impl<'a> std::convert::From<Window<'a>> for Over<'a> {
+    #[inline]
+    fn from(val: Window<'a>) -> Self {
+        Over::Spec(val)
+    }
+}
+
+/// Window specification, used in `OVER (..)` and `WINDOW` clause.
+///
+/// Converting from a `&str` makes a spec refining an existing named window,
+/// as in `OVER (w ROWS ...)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Window<'a> {
+    pub(crate) base: Option<Ident<'a>>,
+    pub(crate) partitions: Vec<Expr<'a>>,
+    pub(crate) orders: Vec<Order<'a>>,
+    pub(crate) frame: Option<Frame>,
+}
+
+crate::macros::gen_display!(Window<'_>);
+
+impl<'a> std::convert::From<&'a str> for Window<'a> {
+    #[inline]
+    fn from(val: &'a str) -> Self {
+        Window {
+            base: Some(val.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Window<'a> {
+    /// Add `PARTITION BY` expressions.
+    pub fn partition_by<R>(mut self, exprs: R) -> Window<'a>
+    where
+        R: Into<Row<'a>>,
+    {
+        self.partitions.extend(exprs.into().0);
+        self
+    }
+
+    /// Add `ORDER BY` items.
+    pub fn order_by<O>(mut self, orders: O) -> Window<'a>
+    where
+        O: Into<crate::clause::OrderBy<'a>>,
+    {
+        self.orders.extend(orders.into().0);
+        self
+    }
+
+    /// Set frame to `ROWS BETWEEN start AND end`.
+    pub fn rows(mut self, start: FrameBound, end: FrameBound) -> Window<'a> {
+        self.frame = Some(Frame(FrameUnit::Rows, start, end));
+        self
+    }
+
+    /// Set frame to `RANGE BETWEEN start AND end`.
+    pub fn range(mut self, start: FrameBound, end: FrameBound) -> Window<'a> {
+        self.frame = Some(Frame(FrameUnit::Range, start, end));
+        self
+    }
+
+    /// Set frame to `GROUPS BETWEEN start AND end`, not supported by mysql.
+    pub fn groups(mut self, start: FrameBound, end: FrameBound) -> Window<'a> {
+        self.frame = Some(Frame(FrameUnit::Groups, start, end));
+        self
+    }
+}
+
+/// Frame of a [`Window`], `unit BETWEEN start AND end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame(
+    pub(crate) FrameUnit,
+    pub(crate) FrameBound,
+    pub(crate) FrameBound,
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameUnit {
+    Rows,
+    Range,
+    Groups,
+}
+
+/// Start or end of a [`Frame`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FuncRef<'a> {
     Func(Ident<'a>),
@@ -200,6 +344,8 @@ where
 pub struct Table<'a> {
     pub table: TableExpr<'a>,
     pub alias: Option<Ident<'a>>,
+    /// Column aliases, typed for functions returning `record`.
+    pub(crate) columns: Vec<(Ident<'a>, Option<crate::ddl::Type<'a>>)>,
 }
 
 crate::macros::gen_display!(Table<'_>);
@@ -213,10 +359,64 @@ where
         Table {
             table: val.into(),
             alias: None,
+            columns: Vec::new(),
         }
     }
 }
 
+impl<'a> Table<'a> {
+    /// Alias the columns of the table, `AS alias (a, b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::{as_table, func};
+    ///
+    /// assert_eq!(
+    ///     as_table(func("generate_series", [1, 3]), "t").columns(["n"]).to_string(),
+    ///     "generate_series(1, 3) AS t (n)",
+    /// );
+    /// ```
+    pub fn columns<I, C>(mut self, columns: I) -> Table<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.columns = columns
+            .into_iter()
+            .map(|column| (column.into(), None))
+            .collect();
+        self
+    }
+
+    /// Define the columns of a function returning `record`, postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::{as_table, func};
+    /// use xql::ddl::Type;
+    ///
+    /// assert_eq!(
+    ///     as_table(func("json_to_recordset", ["data"]), "r")
+    ///         .column_types([("a", Type::Int), ("b", Type::Text)])
+    ///         .to_string(),
+    ///     "json_to_recordset(data) AS r (a INTEGER, b TEXT)",
+    /// );
+    /// ```
+    pub fn column_types<I, C>(mut self, columns: I) -> Table<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = (C, crate::ddl::Type<'a>)>,
+    {
+        self.columns = columns
+            .into_iter()
+            .map(|(column, ty)| (column.into(), Some(ty)))
+            .collect();
+        self
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Row<'a>(pub(crate) Vec<Expr<'a>>);
@@ -231,6 +431,31 @@ pub struct Cte<'a> {
     pub(crate) name: Ident<'a>,
     pub(crate) columns: Vec<Ident<'a>>,
     pub(crate) stmt: crate::stmt::Stmt<'a>,
+    /// `AS MATERIALIZED` when `true`, `AS NOT MATERIALIZED` when `false`.
+    pub(crate) materialized: Option<bool>,
+    pub(crate) search: Option<Search<'a>>,
+    pub(crate) cycle: Option<Cycle<'a>>,
 }
 
 crate::macros::gen_display!(Cte<'_>);
+
+/// `SEARCH DEPTH FIRST BY columns SET column` of a recursive [`Cte`],
+/// `BREADTH FIRST` when `breadth` is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Search<'a> {
+    pub(crate) breadth: bool,
+    pub(crate) by: Vec<Ident<'a>>,
+    pub(crate) set: Ident<'a>,
+}
+
+crate::macros::gen_display!(Search<'_>);
+
+/// `CYCLE columns SET column USING column` of a recursive [`Cte`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<'a> {
+    pub(crate) columns: Vec<Ident<'a>>,
+    pub(crate) set: Ident<'a>,
+    pub(crate) using: Ident<'a>,
+}
+
+crate::macros::gen_display!(Cycle<'_>);
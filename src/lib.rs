@@ -4,6 +4,8 @@
 
 pub mod blanket;
 pub mod clause;
+pub mod ddl;
+pub mod error;
 pub mod expr;
 pub mod func;
 pub mod item;
@@ -19,9 +21,25 @@ mod build;
 mod macros;
 
 // re exports statement builder
+pub use stmt::alter_enum;
+pub use stmt::alter_table;
+pub use stmt::create_enum;
+pub use stmt::create_index;
+pub use stmt::create_schema;
+pub use stmt::create_sequence;
+pub use stmt::create_table;
+pub use stmt::create_view;
 pub use stmt::delete;
+pub use stmt::drop_index;
+pub use stmt::drop_schema;
+pub use stmt::drop_table;
+pub use stmt::drop_view;
+pub use stmt::explain;
 pub use stmt::insert;
+pub use stmt::merge;
+pub use stmt::refresh_view;
 pub use stmt::select;
+pub use stmt::truncate;
 pub use stmt::update;
 pub use stmt::values;
 pub use stmt::{except, except_all, intersect, intersect_all, union, union_all};
@@ -31,17 +49,25 @@ pub use func::func;
 pub use func::{avg, count, max, min, sum};
 
 // re exports ops
+pub use ops::excluded;
 pub use ops::{add, div, mul, rem, sub};
 pub use ops::{and, not, or};
-pub use ops::{as_field, as_table, asc, desc, paren};
+pub use ops::{as_field, as_table, asc, desc, lateral, paren};
 pub use ops::{binop, postop, preop};
+pub use ops::{cube, grouping, grouping_sets, rollup};
 pub use ops::{eq, ge, gt, le, lt, ne};
 pub use ops::{ilike, isnull, like};
+pub use ops::{rows_from, with_ordinality};
 
 pub use ops::{cross_join, join, natural_join};
 pub use ops::{full_join, left_join, right_join};
+pub use ops::{full_join_using, join_using, left_join_using, right_join_using};
 pub use ops::{natural_full_join, natural_left_join, natural_right_join};
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use schema::Schema;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use value::Value;
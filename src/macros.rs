@@ -246,11 +246,17 @@ macro_rules! gen_impl_from_tup {
 
 macro_rules! gen_display {
     ($type:ty) => {
+        /// Render with the generic dialect, inlining every value.
+        ///
+        /// A statement which can not be rendered, such as a malformed one,
+        /// renders as its error inside an SQL comment, which is not valid SQL.
+        /// Use the dialect specific rendering to get the error itself.
         impl ::std::fmt::Display for $type {
             fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                let (sql, ..) =
-                    $crate::build::ToSql::to_sql::<$crate::build::Display>(self.clone());
-                write!(f, "{sql}")
+                match $crate::build::ToSql::to_sql::<$crate::build::Display>(self.clone()) {
+                    Ok((sql, ..)) => write!(f, "{sql}"),
+                    Err(err) => write!(f, "/* {err} */"),
+                }
             }
         }
     };
@@ -4,6 +4,7 @@ use crate::item::Field;
 use crate::item::Table;
 use crate::item::Ident;
 use crate::item::Order;
+use crate::item::Row;
 use crate::item::Sort;
 
 /// Make an alias out of an expression.
@@ -45,6 +46,7 @@ where
     Table {
         table: table.into(),
         alias: Some(alias.into()),
+        columns: Vec::new(),
     }
 }
 
@@ -353,16 +355,229 @@ where
     postop(expr, "ISNULL")
 }
 
+/// Refer to the value proposed for insertion inside `ON CONFLICT DO UPDATE`.
+///
+/// # Examples
+///
+/// ```
+/// use xql::excluded;
+/// use xql::insert;
+///
+/// assert_eq!(
+///     insert("book", ["id", "title"])
+///         .values([(1, &"Dune".to_string())])
+///         .on_conflict(["id"])
+///         .do_update([("title", excluded("title"))])
+///         .to_string(),
+///     "INSERT INTO book(id, title) VALUES (1, 'Dune') ON CONFLICT (id) DO UPDATE SET title = excluded.title",
+/// );
+/// ```
+#[inline]
+pub fn excluded<'a, C>(column: C) -> Expr<'a>
+where
+    C: Into<Ident<'a>>,
+{
+    Expr::Excluded(column.into())
+}
+
+/// Make a `ROLLUP` grouping element, producing subtotal rows for every prefix
+/// of `exprs`.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{count, rollup, select};
+///
+/// assert_eq!(
+///     select(["year", "lang"]).select([count("id")]).from("book").group_by([rollup(("year", "lang"))]).to_string(),
+///     "SELECT year, lang, count(id) FROM book GROUP BY ROLLUP (year, lang)",
+/// );
+/// ```
+#[inline]
+pub fn rollup<'a, R>(exprs: R) -> Expr<'a>
+where
+    R: Into<Row<'a>>,
+{
+    Expr::Rollup(exprs.into().0)
+}
+
+/// Make a `CUBE` grouping element, producing subtotal rows for every subset
+/// of `exprs`.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{count, cube, select};
+///
+/// assert_eq!(
+///     select(["year", "lang"]).select([count("id")]).from("book").group_by([cube(("year", "lang"))]).to_string(),
+///     "SELECT year, lang, count(id) FROM book GROUP BY CUBE (year, lang)",
+/// );
+/// ```
+#[inline]
+pub fn cube<'a, R>(exprs: R) -> Expr<'a>
+where
+    R: Into<Row<'a>>,
+{
+    Expr::Cube(exprs.into().0)
+}
+
+/// Make a `GROUPING SETS` grouping element. An empty set is the grand total.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{count, grouping_sets, select};
+/// use xql::expr::Expr;
+///
+/// assert_eq!(
+///     select(["year", "lang"])
+///         .select([count("id")])
+///         .from("book")
+///         .group_by([grouping_sets([vec!["year".into()], vec!["year".into(), "lang".into()], Vec::<Expr>::new()])])
+///         .to_string(),
+///     "SELECT year, lang, count(id) FROM book GROUP BY GROUPING SETS ((year), (year, lang), ())",
+/// );
+/// ```
+#[inline]
+pub fn grouping_sets<'a, I, R>(sets: I) -> Expr<'a>
+where
+    I: IntoIterator<Item = R>,
+    R: Into<Row<'a>>,
+{
+    Expr::GroupingSets(sets.into_iter().map(|set| set.into().0).collect())
+}
+
+/// Call `GROUPING` which has a bit set for every argument not grouped in the
+/// current row.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{as_field, grouping, rollup, select};
+///
+/// assert_eq!(
+///     select(["year"]).select([as_field(grouping(["year"]), "total")]).from("book").group_by([rollup(["year"])]).to_string(),
+///     "SELECT year, GROUPING(year) AS total FROM book GROUP BY ROLLUP (year)",
+/// );
+/// ```
+#[inline]
+pub fn grouping<'a, R>(exprs: R) -> Expr<'a>
+where
+    R: Into<Row<'a>>,
+{
+    Expr::Grouping(exprs.into().0)
+}
+
+/// Make a `LATERAL` subquery, which may refer to tables preceding it.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{eq, lateral, left_join, select};
+///
+/// assert_eq!(
+///     left_join(
+///         "author",
+///         lateral(select(["title"]).from("book").filter(eq("author_id", ("author", "id"))), "book"),
+///         true,
+///     ).to_string(),
+///     "author LEFT JOIN LATERAL (SELECT title FROM book WHERE author_id = author.id) AS book ON true",
+/// );
+/// ```
+#[inline]
+pub fn lateral<'a, S, A>(subquery: S, alias: A) -> TableExpr<'a>
+where
+    S: Into<crate::stmt::result::Result<'a>>,
+    A: Into<Ident<'a>>,
+{
+    TableExpr::Lateral(Box::new(subquery.into()), alias.into())
+}
+
+/// Zip the rows of set returning functions, `ROWS FROM (..)`.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{func, rows_from};
+///
+/// assert_eq!(
+///     rows_from([func("generate_series", [1, 3]), func("unnest", ["tags"])]).to_string(),
+///     "ROWS FROM (generate_series(1, 3), unnest(tags))",
+/// );
+/// ```
+#[inline]
+pub fn rows_from<'a, I, F>(funcs: I) -> TableExpr<'a>
+where
+    F: Into<crate::item::FuncCall<'a>>,
+    I: IntoIterator<Item = F>,
+{
+    TableExpr::RowsFrom(funcs.into_iter().map(Into::into).collect(), false)
+}
+
+/// Number the rows of set returning functions, `.. WITH ORDINALITY`.
+///
+/// # Examples
+///
+/// ```
+/// use xql::{as_table, func, with_ordinality};
+///
+/// assert_eq!(
+///     as_table(with_ordinality([func("unnest", ["tags"])]), "t")
+///         .columns(["tag", "idx"])
+///         .to_string(),
+///     "unnest(tags) WITH ORDINALITY AS t (tag, idx)",
+/// );
+/// ```
+#[inline]
+pub fn with_ordinality<'a, I, F>(funcs: I) -> TableExpr<'a>
+where
+    F: Into<crate::item::FuncCall<'a>>,
+    I: IntoIterator<Item = F>,
+{
+    TableExpr::RowsFrom(funcs.into_iter().map(Into::into).collect(), true)
+}
+
+/// Turn a join operand into a table expression, keeping its alias if any.
+fn operand<'a, T>(table: T) -> TableExpr<'a>
+where
+    T: Into<Table<'a>>,
+{
+    let table = table.into();
+    if table.alias.is_none() && table.columns.is_empty() {
+        table.table
+    } else {
+        TableExpr::Aliased(Box::new(table))
+    }
+}
+
 macro_rules! generate_join_funcs {
     ($(#[$comment:meta])* $join:ident $fn:ident) => {
         $(#[$comment])*
         #[inline]
         pub fn $fn<'a, L, R>(left: L, right: R) -> crate::table_expr::TableExpr<'a>
         where
-            L: Into<crate::table_expr::TableExpr<'a>>,
-            R: Into<crate::table_expr::TableExpr<'a>>,
+            L: Into<Table<'a>>,
+            R: Into<Table<'a>>,
         {
-            crate::table_expr::TableExpr::$join(Box::new(left.into()), Box::new(right.into()))
+            crate::table_expr::TableExpr::$join(Box::new(operand(left)), Box::new(operand(right)))
+        }
+    };
+    ($(#[$comment:meta])* $join:ident $fn:ident using) => {
+        $(#[$comment])*
+        #[inline]
+        pub fn $fn<'a, L, R, I, C>(left: L, right: R, columns: I) -> crate::table_expr::TableExpr<'a>
+        where
+            L: Into<Table<'a>>,
+            R: Into<Table<'a>>,
+            C: Into<crate::item::Ident<'a>>,
+            I: IntoIterator<Item = C>,
+        {
+            crate::table_expr::TableExpr::$join(
+                Box::new(operand(left)),
+                Box::new(operand(right)),
+                columns.into_iter().map(Into::into).collect(),
+            )
         }
     };
     ($(#[$comment:meta])* $join:ident $fn:ident cond) => {
@@ -370,19 +585,75 @@ macro_rules! generate_join_funcs {
         #[inline]
         pub fn $fn<'a, L, R, E>(left: L, right: R, cond: E) -> crate::table_expr::TableExpr<'a>
         where
-            L: Into<crate::table_expr::TableExpr<'a>>,
-            R: Into<crate::table_expr::TableExpr<'a>>,
+            L: Into<Table<'a>>,
+            R: Into<Table<'a>>,
             E: Into<crate::expr::Expr<'a>>,
         {
             crate::table_expr::TableExpr::$join(
-                Box::new(left.into()),
-                Box::new(right.into()),
+                Box::new(operand(left)),
+                Box::new(operand(right)),
                 cond.into(),
             )
         }
     };
 }
 
+generate_join_funcs!(
+    /// Construct a `JOIN ... USING (columns)` operation on a table expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use xql::join_using;
+    /// 
+    /// assert_eq!(
+    ///     join_using("book", "review", ["book_id"]).to_string(),
+    ///     "book JOIN review USING (book_id)",
+    /// );
+    /// ```
+    JoinUsing join_using using);
+generate_join_funcs!(
+    /// Construct a `LEFT JOIN ... USING (columns)` operation on a table expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use xql::left_join_using;
+    /// 
+    /// assert_eq!(
+    ///     left_join_using("book", "review", ["book_id"]).to_string(),
+    ///     "book LEFT JOIN review USING (book_id)",
+    /// );
+    /// ```
+    LeftJoinUsing left_join_using using);
+generate_join_funcs!(
+    /// Construct a `RIGHT JOIN ... USING (columns)` operation on a table expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use xql::right_join_using;
+    /// 
+    /// assert_eq!(
+    ///     right_join_using("book", "review", ["book_id"]).to_string(),
+    ///     "book RIGHT JOIN review USING (book_id)",
+    /// );
+    /// ```
+    RightJoinUsing right_join_using using);
+generate_join_funcs!(
+    /// Construct a `FULL JOIN ... USING (columns)` operation on a table expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use xql::full_join_using;
+    /// 
+    /// assert_eq!(
+    ///     full_join_using("book", "review", ["book_id"]).to_string(),
+    ///     "book FULL JOIN review USING (book_id)",
+    /// );
+    /// ```
+    FullJoinUsing full_join_using using);
 generate_join_funcs!(
     /// Construct a `JOIN` operation on a table expression.
     /// 
@@ -0,0 +1,66 @@
+use crate::item::TableRef;
+
+/// `ALTER TYPE name ADD VALUE ..` statement builder, postgres only.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AlterEnum<'a> {
+    pub(crate) name: TableRef<'a>,
+    pub(crate) value: &'a str,
+    pub(crate) if_not_exists: bool,
+    pub(crate) position: Option<(&'static str, &'a str)>,
+}
+
+crate::macros::gen_display!(AlterEnum<'_>);
+
+impl<'a> std::convert::From<AlterEnum<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: AlterEnum<'a>) -> Self {
+        crate::stmt::Stmt::AlterEnum(val)
+    }
+}
+
+impl<'a> AlterEnum<'a> {
+    /// Turn into `ADD VALUE IF NOT EXISTS`.
+    pub fn if_not_exists(mut self) -> AlterEnum<'a> {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Place the new value before `value` instead of last.
+    pub fn before(mut self, value: &'a str) -> AlterEnum<'a> {
+        self.position = Some(("BEFORE", value));
+        self
+    }
+
+    /// Place the new value after `value` instead of last.
+    pub fn after(mut self, value: &'a str) -> AlterEnum<'a> {
+        self.position = Some(("AFTER", value));
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::alter_enum("status", "archived")
+        .if_not_exists()
+        .after("published");
+    assert_eq!(
+        query.to_string(),
+        "ALTER TYPE status ADD VALUE IF NOT EXISTS 'archived' AFTER 'published'"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "ALTER TYPE \"status\" ADD VALUE IF NOT EXISTS 'archived' AFTER 'published'"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -0,0 +1,255 @@
+use crate::ddl;
+use crate::item::Ident;
+use crate::item::TableRef;
+use crate::stmt::create_index::CreateIndex;
+use crate::stmt::create_table::CreateTable;
+
+/// `ALTER TABLE` statement builder.
+///
+/// Actions are rendered natively where the dialect supports them, several
+/// statements being separated by `;` when needed. Postgres and mysql can not
+/// prepare several statements at once, building those is an error and they
+/// have to be run one by one from [`to_statements`](AlterTable::to_statements).
+///
+/// Sqlite can only add, drop or rename columns, anything else rebuilds the
+/// table from its current definition given to [`schema`](AlterTable::schema):
+///
+/// ```sql
+/// PRAGMA foreign_keys = OFF;
+/// SAVEPOINT "__alter_t";
+/// CREATE TABLE "__new_t" (...);
+/// INSERT INTO "__new_t" (...) SELECT ... FROM "t";
+/// DROP TABLE "t";
+/// ALTER TABLE "__new_t" RENAME TO "t";
+/// CREATE INDEX ...;
+/// PRAGMA foreign_key_check;
+/// RELEASE "__alter_t";
+/// PRAGMA foreign_keys = ON
+/// ```
+///
+/// Only the indexes given to [`index`](AlterTable::index) are recreated,
+/// triggers and views of the table are not. Foreign key violations are
+/// returned as rows of `PRAGMA foreign_key_check`, run the statements one by
+/// one to roll back to the savepoint on any.
+///
+/// Sqlite ignores `PRAGMA foreign_keys` inside a transaction, where dropping
+/// the table would apply the foreign key actions of referencing tables: the
+/// rebuild has to run outside of a transaction, the savepoint standing for
+/// one. Foreign keys are enabled again afterward, unless
+/// [`foreign_keys_off`](AlterTable::foreign_keys_off) tells they were not.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AlterTable<'a> {
+    pub(crate) table: TableRef<'a>,
+    pub(crate) actions: Vec<ddl::AlterAction<'a>>,
+    pub(crate) schema: Option<CreateTable<'a>>,
+    pub(crate) indexes: Vec<CreateIndex<'a>>,
+    pub(crate) foreign_keys_off: bool,
+}
+
+crate::macros::gen_display!(AlterTable<'_>);
+
+impl<'a> std::convert::From<AlterTable<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: AlterTable<'a>) -> Self {
+        crate::stmt::Stmt::AlterTable(val)
+    }
+}
+
+impl<'a> AlterTable<'a> {
+    /// `ADD COLUMN column`
+    pub fn add_column(mut self, column: ddl::Column<'a>) -> AlterTable<'a> {
+        self.actions
+            .push(ddl::AlterAction::AddColumn(Box::new(column)));
+        self
+    }
+
+    /// `DROP COLUMN column`
+    pub fn drop_column<C>(mut self, column: C) -> AlterTable<'a>
+    where
+        C: Into<Ident<'a>>,
+    {
+        self.actions
+            .push(ddl::AlterAction::DropColumn(column.into()));
+        self
+    }
+
+    /// `RENAME COLUMN from TO to`
+    pub fn rename_column<F, T>(mut self, from: F, to: T) -> AlterTable<'a>
+    where
+        F: Into<Ident<'a>>,
+        T: Into<Ident<'a>>,
+    {
+        self.actions
+            .push(ddl::AlterAction::RenameColumn(from.into(), to.into()));
+        self
+    }
+
+    /// `ALTER COLUMN column TYPE ty`, `MODIFY COLUMN` on mysql.
+    pub fn alter_type<C>(mut self, column: C, ty: ddl::Type<'a>) -> AlterTable<'a>
+    where
+        C: Into<Ident<'a>>,
+    {
+        self.actions
+            .push(ddl::AlterAction::AlterType(column.into(), ty));
+        self
+    }
+
+    /// `ADD constraint`
+    pub fn add_constraint(mut self, constraint: ddl::Constraint<'a>) -> AlterTable<'a> {
+        self.actions
+            .push(ddl::AlterAction::AddConstraint(constraint));
+        self
+    }
+
+    /// `DROP CONSTRAINT name`
+    pub fn drop_constraint<N>(mut self, name: N) -> AlterTable<'a>
+    where
+        N: Into<Ident<'a>>,
+    {
+        self.actions
+            .push(ddl::AlterAction::DropConstraint(name.into()));
+        self
+    }
+
+    /// Current definition of the table, needed by sqlite to rebuild it.
+    ///
+    /// Renamed columns are updated in the constraint column lists, but not
+    /// inside `CHECK` or generated column expressions.
+    pub fn schema(mut self, schema: CreateTable<'a>) -> AlterTable<'a> {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Index of the table, recreated by sqlite after a rebuild.
+    ///
+    /// Like [`schema`](AlterTable::schema), renamed columns are updated, but
+    /// not inside expressions.
+    pub fn index(mut self, index: CreateIndex<'a>) -> AlterTable<'a> {
+        self.indexes.push(index);
+        self
+    }
+
+    /// The connection does not enforce foreign keys, sqlite rebuilds leave
+    /// `PRAGMA foreign_keys` alone.
+    pub fn foreign_keys_off(mut self) -> AlterTable<'a> {
+        self.foreign_keys_off = true;
+        self
+    }
+
+    /// Render into separate statements for dialect `D`, for backends which
+    /// can not execute several statements at once.
+    pub fn to_statements<D>(self) -> crate::error::Result<Vec<String>>
+    where
+        D: crate::build::Dialect,
+    {
+        crate::build::alter_table::<D>(self)
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ddl::{check, column, Type};
+    use crate::ops::gt;
+
+    let query = crate::stmt::alter_table("book")
+        .add_column(column("isbn", Type::Varchar(13)).unique())
+        .alter_type("price", Type::Numeric(12, 2))
+        .rename_column("name", "title")
+        .add_constraint(check(gt("price", 0)).named("positive_price"))
+        .drop_constraint("book_old");
+    assert_eq!(
+        query.to_string(),
+        "ALTER TABLE book ADD COLUMN isbn VARCHAR(13) UNIQUE, ALTER COLUMN price TYPE NUMERIC(12, 2); \
+         ALTER TABLE book RENAME COLUMN name TO title; \
+         ALTER TABLE book ADD CONSTRAINT positive_price CHECK (price > 0), DROP CONSTRAINT book_old"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_statements::<sqlx::Postgres>().unwrap(),
+        [
+            "ALTER TABLE \"book\" ADD COLUMN \"isbn\" VARCHAR(13) UNIQUE, ALTER COLUMN \"price\" TYPE NUMERIC(12, 2)",
+            "ALTER TABLE \"book\" RENAME COLUMN \"name\" TO \"title\"",
+            "ALTER TABLE \"book\" ADD CONSTRAINT \"positive_price\" CHECK (\"price\" > 0), DROP CONSTRAINT \"book_old\"",
+        ]
+    );
+
+    #[cfg(feature = "postgres")]
+    assert!(query.clone().to_sql::<sqlx::Postgres>().is_err());
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "ALTER TABLE `book` ADD COLUMN `isbn` VARCHAR(13) UNIQUE, MODIFY COLUMN `price` DECIMAL(12, 2), \
+         RENAME COLUMN `name` TO `title`, ADD CONSTRAINT `positive_price` CHECK (`price` > 0), DROP CONSTRAINT `book_old`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+    let schema = crate::stmt::create_table("book")
+        .column(column("id", Type::Int).primary_key())
+        .column(column("name", Type::Text))
+        .column(column("price", Type::Int));
+    let query = crate::stmt::alter_table("book")
+        .schema(schema)
+        .index(crate::stmt::create_index("book_name").on("book", ["name"]))
+        .rename_column("name", "title")
+        .alter_type("price", Type::Numeric(12, 2))
+        .add_column(column("isbn", Type::Text));
+    assert_eq!(
+        query.to_string(),
+        "ALTER TABLE book RENAME COLUMN name TO title; \
+         ALTER TABLE book ALTER COLUMN price TYPE NUMERIC(12, 2), ADD COLUMN isbn TEXT"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_statements::<sqlx::Sqlite>().unwrap(),
+        [
+            "PRAGMA foreign_keys = OFF",
+            "SAVEPOINT \"__alter_book\"",
+            "CREATE TABLE \"__new_book\" (\"id\" INTEGER PRIMARY KEY, \"title\" TEXT, \"price\" NUMERIC, \"isbn\" TEXT)",
+            "INSERT INTO \"__new_book\" (\"id\", \"title\", \"price\") SELECT \"id\", \"name\", \"price\" FROM \"book\"",
+            "DROP TABLE \"book\"",
+            "ALTER TABLE \"__new_book\" RENAME TO \"book\"",
+            "CREATE INDEX \"book_name\" ON \"book\" (\"title\")",
+            "PRAGMA foreign_key_check",
+            "RELEASE \"__alter_book\"",
+            "PRAGMA foreign_keys = ON",
+        ]
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        crate::stmt::alter_table(("main", "book"))
+            .schema(crate::stmt::create_table(("main", "book")).column(column("id", Type::Int)))
+            .alter_type("id", Type::Text)
+            .foreign_keys_off()
+            .to_statements::<sqlx::Sqlite>()
+            .unwrap(),
+        [
+            "SAVEPOINT \"__alter_book\"",
+            "CREATE TABLE \"main\".\"__new_book\" (\"id\" TEXT)",
+            "INSERT INTO \"main\".\"__new_book\" (\"id\") SELECT \"id\" FROM \"main\".\"book\"",
+            "DROP TABLE \"main\".\"book\"",
+            "ALTER TABLE \"main\".\"__new_book\" RENAME TO \"book\"",
+            "PRAGMA \"main\".foreign_key_check",
+            "RELEASE \"__alter_book\"",
+        ]
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        crate::stmt::alter_table("book")
+            .drop_column("price")
+            .rename_column("name", "title")
+            .to_sql::<sqlx::Sqlite>()
+            .unwrap()
+            .0,
+        "ALTER TABLE \"book\" DROP COLUMN \"price\"; ALTER TABLE \"book\" RENAME COLUMN \"name\" TO \"title\""
+    );
+}
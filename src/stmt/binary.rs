@@ -11,11 +11,63 @@ pub struct Binary<'a> {
     pub(crate) left: Box<Result<'a>>,
     pub(crate) op: &'static str,
     pub(crate) right: Box<Result<'a>>,
+    pub(crate) orders: Option<clause::OrderBy<'a>>,
 }
 
 stmt_common!(Binary);
 crate::macros::gen_display!(Binary<'_>);
 
+impl<'a> Binary<'a> {
+    /// Add `ORDER BY` clause to the combined result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::select;
+    /// use xql::union;
+    ///
+    /// assert_eq!(
+    ///     union(select(["id"]).from("book"), select(["id"]).from("draft"))
+    ///         .order_by(["id"])
+    ///         .limit(10)
+    ///         .to_string(),
+    ///     "SELECT id FROM book UNION SELECT id FROM draft ORDER BY id LIMIT 10",
+    /// );
+    /// ```
+    pub fn order_by<O>(mut self, orders: O) -> Binary<'a>
+    where
+        O: Into<clause::OrderBy<'a>>,
+    {
+        self.orders = match self.orders.take() {
+            Some(mut inner) => {
+                inner.0.extend(orders.into().0);
+                Some(inner)
+            }
+            None => Some(orders.into()),
+        };
+        self
+    }
+
+    pub fn limit(self, limit: u32) -> Result<'a> {
+        Result::from(self).limit(limit)
+    }
+
+    pub fn offset(self, offset: u32) -> Result<'a> {
+        Result::from(self).offset(offset)
+    }
+
+    /// Add `FETCH FIRST n ROWS ONLY` clause, see [`Result::fetch_first`].
+    pub fn fetch_first(self, count: u32) -> Result<'a> {
+        Result::from(self).fetch_first(count)
+    }
+
+    /// Add `FETCH FIRST n ROWS WITH TIES` clause, see
+    /// [`Result::fetch_first_with_ties`].
+    pub fn fetch_first_with_ties(self, count: u32) -> Result<'a> {
+        Result::from(self).fetch_first_with_ties(count)
+    }
+}
+
 #[test]
 #[cfg(test)]
 fn test() {
@@ -33,4 +85,12 @@ fn test() {
     assert_eq!(query.to_string(), "SELECT 1 INTERSECT SELECT 2");
     let query = stmt::intersect_all(stmt::select([1]), stmt::select([2]));
     assert_eq!(query.to_string(), "SELECT 1 INTERSECT ALL SELECT 2");
+    let query = stmt::union(stmt::select([1]), stmt::select([2]))
+        .order_by([crate::ops::desc(1)])
+        .offset(1)
+        .limit(1);
+    assert_eq!(
+        query.to_string(),
+        "SELECT 1 UNION SELECT 2 ORDER BY 1 DESC LIMIT 1 OFFSET 1"
+    );
 }
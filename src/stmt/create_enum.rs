@@ -0,0 +1,45 @@
+use crate::item::TableRef;
+
+/// `CREATE TYPE name AS ENUM (..)` statement builder, postgres only.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateEnum<'a> {
+    pub(crate) name: TableRef<'a>,
+    pub(crate) values: Vec<&'a str>,
+}
+
+crate::macros::gen_display!(CreateEnum<'_>);
+
+impl<'a> std::convert::From<CreateEnum<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: CreateEnum<'a>) -> Self {
+        crate::stmt::Stmt::CreateEnum(val)
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::create_enum("status", ["draft", "published", "author's pick"]);
+    assert_eq!(
+        query.to_string(),
+        "CREATE TYPE status AS ENUM ('draft', 'published', 'author''s pick')"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap(),
+        (
+            "CREATE TYPE \"status\" AS ENUM ('draft', 'published', 'author''s pick')".to_string(),
+            Vec::new()
+        )
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -0,0 +1,151 @@
+use crate::clause;
+use crate::expr::Expr;
+use crate::item::Ident;
+use crate::item::TableRef;
+
+/// `CREATE INDEX` statement builder.
+///
+/// Not every dialect has every option: `CONCURRENTLY` is postgres only,
+/// mysql has neither `IF NOT EXISTS` nor partial indexes and sqlite has no
+/// index methods. Building those is an error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateIndex<'a> {
+    pub(crate) name: Ident<'a>,
+    pub(crate) unique: bool,
+    pub(crate) concurrently: bool,
+    pub(crate) if_not_exists: bool,
+    pub(crate) table: TableRef<'a>,
+    pub(crate) method: Option<&'a str>,
+    pub(crate) columns: Vec<crate::item::Order<'a>>,
+    pub(crate) filter: Option<Expr<'a>>,
+}
+
+crate::macros::gen_display!(CreateIndex<'_>);
+
+impl<'a> std::convert::From<CreateIndex<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: CreateIndex<'a>) -> Self {
+        crate::stmt::Stmt::CreateIndex(val)
+    }
+}
+
+impl<'a> CreateIndex<'a> {
+    /// Index `columns` of `table`. A column may be any expression, with an
+    /// optional sort order.
+    pub fn on<T, C>(mut self, table: T, columns: C) -> CreateIndex<'a>
+    where
+        T: Into<TableRef<'a>>,
+        C: Into<clause::OrderBy<'a>>,
+    {
+        self.table = table.into();
+        self.columns = columns.into().0;
+        self
+    }
+
+    /// Turn into `CREATE UNIQUE INDEX`.
+    pub fn unique(mut self) -> CreateIndex<'a> {
+        self.unique = true;
+        self
+    }
+
+    /// Build the index without locking writes, postgres only.
+    pub fn concurrently(mut self) -> CreateIndex<'a> {
+        self.concurrently = true;
+        self
+    }
+
+    /// Turn into `CREATE INDEX IF NOT EXISTS`.
+    pub fn if_not_exists(mut self) -> CreateIndex<'a> {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set index method such as `btree`, `hash` or `gin`.
+    pub fn using(mut self, method: &'a str) -> CreateIndex<'a> {
+        self.method = Some(method);
+        self
+    }
+
+    /// Make a partial index of rows matching `expr`.
+    pub fn filter<E>(mut self, expr: E) -> CreateIndex<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.filter = Some(expr.into());
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::func::func;
+    use crate::item::{Order, Sort};
+    use crate::ops::{eq, isnull};
+
+    let query = crate::stmt::create_index("book_title")
+        .unique()
+        .if_not_exists()
+        .on(
+            "book",
+            [
+                Order::from(func("lower", ["title"])),
+                ("year", Sort::Desc).into(),
+            ],
+        )
+        .filter(isnull("deleted_at"));
+    assert_eq!(
+        query.to_string(),
+        "CREATE UNIQUE INDEX IF NOT EXISTS book_title ON book ((lower(title)), year DESC) WHERE deleted_at ISNULL"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap(),
+        (
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"book_title\" ON \"book\" \
+             ((\"lower\"(\"title\")), \"year\" DESC) WHERE \"deleted_at\" ISNULL"
+                .to_string(),
+            Vec::new()
+        )
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.to_sql::<sqlx::Sqlite>().unwrap().0,
+        "CREATE UNIQUE INDEX IF NOT EXISTS \"book_title\" ON \"book\" \
+         ((\"lower\"(\"title\")), \"year\" DESC) WHERE \"deleted_at\" ISNULL"
+    );
+
+    let query = crate::stmt::create_index("book_tags")
+        .concurrently()
+        .on("book", ["tags"])
+        .using("gin")
+        .filter(eq("published", true));
+    assert_eq!(
+        query.to_string(),
+        "CREATE INDEX CONCURRENTLY book_tags ON book USING gin (tags) WHERE published = true"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        crate::stmt::create_index("book_author")
+            .on("book", ["author_id", "year"])
+            .using("btree")
+            .to_sql::<sqlx::MySql>()
+            .unwrap()
+            .0,
+        "CREATE INDEX `book_author` ON `book` (`author_id`, `year`) USING btree"
+    );
+}
@@ -0,0 +1,52 @@
+use crate::item::Ident;
+
+/// `CREATE SCHEMA` statement builder.
+///
+/// Sqlite has no schema besides attached databases, building it is an error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateSchema<'a> {
+    pub(crate) name: Ident<'a>,
+    pub(crate) if_not_exists: bool,
+}
+
+crate::macros::gen_display!(CreateSchema<'_>);
+
+impl<'a> std::convert::From<CreateSchema<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: CreateSchema<'a>) -> Self {
+        crate::stmt::Stmt::CreateSchema(val)
+    }
+}
+
+impl<'a> CreateSchema<'a> {
+    /// Turn into `CREATE SCHEMA IF NOT EXISTS`.
+    pub fn if_not_exists(mut self) -> CreateSchema<'a> {
+        self.if_not_exists = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::create_schema("tenant_1").if_not_exists();
+    assert_eq!(query.to_string(), "CREATE SCHEMA IF NOT EXISTS tenant_1");
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "CREATE SCHEMA IF NOT EXISTS \"tenant_1\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "CREATE SCHEMA IF NOT EXISTS `tenant_1`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -0,0 +1,103 @@
+use crate::item::TableRef;
+
+/// `CREATE SEQUENCE` statement builder, postgres only.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateSequence<'a> {
+    pub(crate) name: TableRef<'a>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) increment: Option<i64>,
+    pub(crate) min_value: Option<i64>,
+    pub(crate) max_value: Option<i64>,
+    pub(crate) start: Option<i64>,
+    pub(crate) cycle: bool,
+}
+
+crate::macros::gen_display!(CreateSequence<'_>);
+
+impl<'a> std::convert::From<CreateSequence<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: CreateSequence<'a>) -> Self {
+        crate::stmt::Stmt::CreateSequence(val)
+    }
+}
+
+impl<'a> CreateSequence<'a> {
+    /// Turn into `CREATE SEQUENCE IF NOT EXISTS`.
+    pub fn if_not_exists(mut self) -> CreateSequence<'a> {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set `INCREMENT BY` step.
+    pub fn increment(mut self, step: i64) -> CreateSequence<'a> {
+        self.increment = Some(step);
+        self
+    }
+
+    /// Set `MINVALUE`.
+    pub fn min_value(mut self, value: i64) -> CreateSequence<'a> {
+        self.min_value = Some(value);
+        self
+    }
+
+    /// Set `MAXVALUE`.
+    pub fn max_value(mut self, value: i64) -> CreateSequence<'a> {
+        self.max_value = Some(value);
+        self
+    }
+
+    /// Set `START WITH` value.
+    pub fn start(mut self, value: i64) -> CreateSequence<'a> {
+        self.start = Some(value);
+        self
+    }
+
+    /// Wrap around when reaching a bound instead of failing.
+    pub fn cycle(mut self) -> CreateSequence<'a> {
+        self.cycle = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::create_sequence("ticket_seq")
+        .if_not_exists()
+        .increment(10)
+        .min_value(1)
+        .max_value(1000)
+        .start(100)
+        .cycle();
+    assert_eq!(
+        query.to_string(),
+        "CREATE SEQUENCE IF NOT EXISTS ticket_seq INCREMENT BY 10 MINVALUE 1 MAXVALUE 1000 START WITH 100 CYCLE"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "CREATE SEQUENCE IF NOT EXISTS \"ticket_seq\" INCREMENT BY 10 MINVALUE 1 MAXVALUE 1000 START WITH 100 CYCLE"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+    let query = crate::stmt::select([crate::func::nextval(("app", "ticket_seq"))]);
+    assert_eq!(query.to_string(), "SELECT nextval('app.ticket_seq')");
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "SELECT nextval('\"app\".\"ticket_seq\"')"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -0,0 +1,182 @@
+use crate::ddl;
+use crate::item::TableRef;
+
+/// `CREATE TABLE` statement builder.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateTable<'a> {
+    pub(crate) table: TableRef<'a>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) columns: Vec<ddl::Column<'a>>,
+    pub(crate) constraints: Vec<ddl::Constraint<'a>>,
+}
+
+crate::macros::gen_display!(CreateTable<'_>);
+
+impl<'a> std::convert::From<CreateTable<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: CreateTable<'a>) -> Self {
+        crate::stmt::Stmt::CreateTable(val)
+    }
+}
+
+impl<'a> CreateTable<'a> {
+    /// Turn into `CREATE TABLE IF NOT EXISTS`.
+    pub fn if_not_exists(mut self) -> CreateTable<'a> {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Add a column definition.
+    pub fn column(mut self, column: ddl::Column<'a>) -> CreateTable<'a> {
+        self.columns.push(column);
+        self
+    }
+
+    /// Add a table constraint.
+    pub fn constraint(mut self, constraint: ddl::Constraint<'a>) -> CreateTable<'a> {
+        self.constraints.push(constraint);
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ddl::{column, foreign_key, primary_key, RefAction, Type};
+    use crate::ops::{gt, mul};
+
+    let query = crate::stmt::create_table("book")
+        .if_not_exists()
+        .column(column("id", Type::Int).primary_key().identity())
+        .column(column("title", Type::Text).not_null().unique())
+        .column(
+            column("price", Type::Numeric(10, 2))
+                .default(0)
+                .check(gt("price", -1)),
+        )
+        .column(column("total", Type::Numeric(12, 2)).generated(mul("price", 2)))
+        .column(
+            column("author_id", Type::Int)
+                .references("author", "id")
+                .on_delete(RefAction::SetNull),
+        );
+    assert_eq!(
+        query.to_string(),
+        "CREATE TABLE IF NOT EXISTS book (\
+         id SERIAL PRIMARY KEY, \
+         title TEXT NOT NULL UNIQUE, \
+         price NUMERIC(10, 2) DEFAULT 0 CHECK (price > -1), \
+         total NUMERIC(12, 2) GENERATED ALWAYS AS (price * 2) STORED, \
+         author_id INTEGER REFERENCES author (id) ON DELETE SET NULL)"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap(),
+        (
+            "CREATE TABLE IF NOT EXISTS \"book\" (\
+             \"id\" SERIAL PRIMARY KEY, \
+             \"title\" TEXT NOT NULL UNIQUE, \
+             \"price\" NUMERIC(10, 2) DEFAULT 0 CHECK (\"price\" > -1), \
+             \"total\" NUMERIC(12, 2) GENERATED ALWAYS AS (\"price\" * 2) STORED, \
+             \"author_id\" INTEGER REFERENCES \"author\" (\"id\") ON DELETE SET NULL)"
+                .to_string(),
+            Vec::new()
+        )
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "CREATE TABLE IF NOT EXISTS `book` (\
+         `id` INT NOT NULL AUTO_INCREMENT PRIMARY KEY, \
+         `title` TEXT NOT NULL UNIQUE, \
+         `price` DECIMAL(10, 2) DEFAULT 0 CHECK (`price` > -1), \
+         `total` DECIMAL(12, 2) GENERATED ALWAYS AS (`price` * 2) STORED, \
+         `author_id` INT, \
+         FOREIGN KEY (`author_id`) REFERENCES `author` (`id`) ON DELETE SET NULL)"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+        "CREATE TABLE IF NOT EXISTS \"book\" (\
+         \"id\" INTEGER PRIMARY KEY AUTOINCREMENT, \
+         \"title\" TEXT NOT NULL UNIQUE, \
+         \"price\" NUMERIC DEFAULT 0 CHECK (\"price\" > -1), \
+         \"total\" NUMERIC GENERATED ALWAYS AS (\"price\" * 2) STORED, \
+         \"author_id\" INTEGER REFERENCES \"author\" (\"id\") ON DELETE SET NULL)"
+    );
+
+    let new = &"new".to_string();
+    let query = crate::stmt::create_table("book_tag")
+        .column(column("book_id", Type::BigInt).not_null())
+        .column(column("tag", Type::Varchar(32)).not_null().default(new))
+        .constraint(primary_key(["book_id", "tag"]))
+        .constraint(
+            foreign_key(["book_id"], "book", ["id"])
+                .named("book_tag_book")
+                .on_delete(RefAction::Cascade),
+        );
+    assert_eq!(
+        query.to_string(),
+        "CREATE TABLE book_tag (\
+         book_id BIGINT NOT NULL, \
+         tag VARCHAR(32) NOT NULL DEFAULT 'new', \
+         PRIMARY KEY (book_id, tag), \
+         CONSTRAINT book_tag_book FOREIGN KEY (book_id) REFERENCES book (id) ON DELETE CASCADE)"
+    );
+
+    assert_eq!(
+        crate::stmt::create_table("log")
+            .column(column("id", Type::Text).identity())
+            .to_string(),
+        "CREATE TABLE log (id TEXT GENERATED BY DEFAULT AS IDENTITY)"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(crate::stmt::create_table("log")
+        .column(column("id", Type::Int).identity())
+        .to_sql::<sqlx::Sqlite>()
+        .is_err());
+
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    {
+        let path = &r"C:\' OR 1=1 -- ".to_string();
+        let query = crate::stmt::create_table("file")
+            .column(column("path", Type::Text).default(path))
+            .column(
+                column("magic", Type::Bytes).default(crate::value::Value::Bytes(&[0xca, 0xfe])),
+            );
+
+        #[cfg(feature = "postgres")]
+        assert_eq!(
+            query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+            r#"CREATE TABLE "file" ("path" TEXT DEFAULT 'C:\'' OR 1=1 -- ', "magic" BYTEA DEFAULT '\xcafe'::bytea)"#
+        );
+
+        #[cfg(feature = "mysql")]
+        assert_eq!(
+            query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+            r"CREATE TABLE `file` (`path` TEXT DEFAULT ('C:\\'' OR 1=1 -- '), `magic` BLOB DEFAULT (X'cafe'))"
+        );
+
+        #[cfg(feature = "mysql")]
+        assert_eq!(
+            crate::stmt::create_table("file")
+                .column(column("size", Type::Int).default(crate::ops::mul(2, 512)))
+                .to_sql::<sqlx::MySql>()
+                .unwrap()
+                .0,
+            "CREATE TABLE `file` (`size` INT DEFAULT (2 * 512))"
+        );
+
+        #[cfg(feature = "sqlite")]
+        assert_eq!(
+            query.to_sql::<sqlx::Sqlite>().unwrap().0,
+            r#"CREATE TABLE "file" ("path" TEXT DEFAULT 'C:\'' OR 1=1 -- ', "magic" BLOB DEFAULT X'cafe')"#
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::item::Ident;
+use crate::item::TableRef;
+use crate::stmt::data::Data;
+
+/// `CREATE VIEW` statement builder.
+///
+/// Views can not take bind parameters, literals of the query are inlined.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateView<'a> {
+    pub(crate) name: TableRef<'a>,
+    pub(crate) or_replace: bool,
+    pub(crate) materialized: bool,
+    pub(crate) columns: Vec<Ident<'a>>,
+    pub(crate) query: Data<'a>,
+}
+
+crate::macros::gen_display!(CreateView<'_>);
+
+impl<'a> std::convert::From<CreateView<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: CreateView<'a>) -> Self {
+        crate::stmt::Stmt::CreateView(val)
+    }
+}
+
+impl<'a> CreateView<'a> {
+    /// Turn into `CREATE OR REPLACE VIEW`, not available in sqlite nor for
+    /// materialized views.
+    pub fn or_replace(mut self) -> CreateView<'a> {
+        self.or_replace = true;
+        self
+    }
+
+    /// Turn into `CREATE MATERIALIZED VIEW`, postgres only.
+    pub fn materialized(mut self) -> CreateView<'a> {
+        self.materialized = true;
+        self
+    }
+
+    /// Name the columns of the view.
+    pub fn columns<I, C>(mut self, columns: I) -> CreateView<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::expr::Expr;
+    use crate::func::count;
+    use crate::ops::gt;
+
+    let cheap = "cheap".to_string();
+    let query = crate::stmt::create_view(
+        "author_stats",
+        crate::stmt::select([Expr::from("author_id"), count("id").into(), (&cheap).into()])
+            .from("book")
+            .filter(gt("price", 10))
+            .group_by(["author_id"]),
+    )
+    .or_replace()
+    .columns(["author_id", "books", "label"]);
+    assert_eq!(
+        query.to_string(),
+        "CREATE OR REPLACE VIEW author_stats (author_id, books, label) AS \
+         SELECT author_id, count(id), 'cheap' FROM book WHERE price > 10 GROUP BY author_id"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap(),
+        (
+            "CREATE OR REPLACE VIEW \"author_stats\" (\"author_id\", \"books\", \"label\") AS \
+             SELECT \"author_id\", \"count\"(\"id\"), 'cheap' FROM \"book\" WHERE \"price\" > 10 GROUP BY \"author_id\""
+                .to_string(),
+            Vec::new()
+        )
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "CREATE OR REPLACE VIEW `author_stats` (`author_id`, `books`, `label`) AS \
+         SELECT `author_id`, `count`(`id`), 'cheap' FROM `book` WHERE `price` > 10 GROUP BY `author_id`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+    let query = crate::stmt::create_view(
+        "book_count",
+        crate::stmt::select([count("id")]).from("book"),
+    )
+    .materialized();
+    assert_eq!(
+        query.to_string(),
+        "CREATE MATERIALIZED VIEW book_count AS SELECT count(id) FROM book"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.clone().to_sql::<sqlx::Sqlite>().is_err());
+
+    #[cfg(feature = "postgres")]
+    assert!(query.or_replace().to_sql::<sqlx::Postgres>().is_err());
+}
@@ -51,6 +51,13 @@ impl<'a> std::convert::From<crate::stmt::select::Select<'a>> for Data<'a> {
     }
 }
 
+impl<'a> std::convert::From<crate::stmt::binary::Binary<'a>> for Data<'a> {
+    #[inline]
+    fn from(val: crate::stmt::binary::Binary<'a>) -> Self {
+        Data::Binary(val)
+    }
+}
+
 impl<'a> std::convert::From<Box<crate::stmt::select::Select<'a>>> for Data<'a> {
     #[inline]
     fn from(val: Box<crate::stmt::select::Select<'a>>) -> Self {
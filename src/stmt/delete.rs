@@ -7,7 +7,10 @@ use crate::ops::and;
 pub struct Delete<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) table: clause::Delete<'a>,
+    pub(crate) using: Option<clause::From<'a>>,
     pub(crate) filter: Option<clause::Where<'a>>,
+    pub(crate) orders: Option<clause::OrderBy<'a>>,
+    pub(crate) limit: Option<clause::Limit>,
     pub(crate) returns: Option<clause::Returning<'a>>,
 }
 
@@ -16,6 +19,42 @@ stmt_common!(Delete);
 crate::macros::gen_display!(Delete<'_>);
 
 impl<'a> Delete<'a> {
+    /// Add table(s) to `USING` clause, deleting rows of the target table
+    /// joined to them by [`filter`](Delete::filter).
+    ///
+    /// Rendered as `DELETE t FROM t JOIN ...` on mysql and emulated with a
+    /// correlated `EXISTS` on sqlite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::delete;
+    /// use xql::eq;
+    ///
+    /// let query = delete("book")
+    ///     .using(["author"])
+    ///     .filter(eq(("book", "author_id"), ("author", "id")))
+    ///     .filter(eq(("author", "banned"), true));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "DELETE FROM book USING author WHERE book.author_id = author.id AND author.banned = true",
+    /// );
+    /// ```
+    pub fn using<T>(mut self, tables: T) -> Delete<'a>
+    where
+        T: Into<clause::From<'a>>,
+    {
+        self.using = match self.using.take() {
+            Some(mut inner) => {
+                inner.0.extend(tables.into().0);
+                Some(inner)
+            }
+            None => Some(tables.into()),
+        };
+        self
+    }
+
     /// Set condition to `WHERE` clause.
     ///
     /// Successive calls combine new condition with previous condition with
@@ -48,6 +87,31 @@ impl<'a> Delete<'a> {
         self
     }
 
+    /// Add `ORDER BY` clause, only meaningful together with
+    /// [`limit`](Delete::limit).
+    pub fn order_by<O>(mut self, orders: O) -> Delete<'a>
+    where
+        O: Into<clause::OrderBy<'a>>,
+    {
+        self.orders = match self.orders.take() {
+            Some(mut inner) => {
+                inner.0.extend(orders.into().0);
+                Some(inner)
+            }
+            None => Some(orders.into()),
+        };
+        self
+    }
+
+    /// Limit number of affected rows.
+    ///
+    /// Native on mysql, emulated with a `rowid` subquery on sqlite and not
+    /// supported on postgres.
+    pub fn limit(mut self, limit: u32) -> Delete<'a> {
+        self.limit = Some(clause::Limit(limit));
+        self
+    }
+
     /// Set/Add field(s) to `RETURNING` clause.
     ///
     /// Successive calls combine adds more field into the clause.
@@ -96,4 +160,52 @@ fn test() {
         query.to_string(),
         "DELETE FROM user WHERE NOT user.active AND user.name ISNULL RETURNING id, name"
     );
+
+    let query = crate::delete("user")
+        .filter(ops::not(("user", "active")))
+        .order_by(["id"])
+        .limit(10);
+    assert_eq!(
+        query.to_string(),
+        "DELETE FROM user WHERE NOT user.active ORDER BY id LIMIT 10"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        crate::build::ToSql::to_sql::<sqlx::Sqlite>(query.clone()).unwrap().0,
+        "DELETE FROM \"user\" WHERE rowid IN (SELECT rowid FROM \"user\" WHERE NOT \"user\".\"active\" ORDER BY \"id\" LIMIT ?)"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert!(crate::build::ToSql::to_sql::<sqlx::Postgres>(query).is_err());
+}
+
+#[test]
+#[cfg(test)]
+fn using() {
+    use crate::ops;
+
+    let query = crate::delete("book")
+        .using(["author"])
+        .filter(ops::eq(("book", "author_id"), ("author", "id")))
+        .filter(("author", "banned"));
+    assert_eq!(
+        query.to_string(),
+        "DELETE FROM book USING author WHERE book.author_id = author.id AND author.banned"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        crate::build::ToSql::to_sql::<sqlx::MySql>(query.clone()).unwrap().0,
+        "DELETE `book` FROM `book` JOIN `author` WHERE `book`.`author_id` = `author`.`id` AND `author`.`banned`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        crate::build::ToSql::to_sql::<sqlx::Sqlite>(query.clone()).unwrap().0,
+        "DELETE FROM \"book\" WHERE EXISTS (SELECT ? FROM \"author\" WHERE \"book\".\"author_id\" = \"author\".\"id\" AND \"author\".\"banned\")"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(crate::build::ToSql::to_sql::<sqlx::MySql>(query.limit(1)).is_err());
 }
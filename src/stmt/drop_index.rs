@@ -0,0 +1,90 @@
+use crate::item::Ident;
+use crate::item::TableRef;
+
+/// `DROP INDEX` statement builder.
+///
+/// Mysql indexes belong to their table, so it needs [`on`](DropIndex::on).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DropIndex<'a> {
+    pub(crate) name: Ident<'a>,
+    pub(crate) concurrently: bool,
+    pub(crate) if_exists: bool,
+    pub(crate) table: Option<TableRef<'a>>,
+}
+
+crate::macros::gen_display!(DropIndex<'_>);
+
+impl<'a> std::convert::From<DropIndex<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: DropIndex<'a>) -> Self {
+        crate::stmt::Stmt::DropIndex(val)
+    }
+}
+
+impl<'a> DropIndex<'a> {
+    /// Set the table of the index, rendered on mysql only.
+    pub fn on<T>(mut self, table: T) -> DropIndex<'a>
+    where
+        T: Into<TableRef<'a>>,
+    {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Drop the index without locking, postgres only.
+    pub fn concurrently(mut self) -> DropIndex<'a> {
+        self.concurrently = true;
+        self
+    }
+
+    /// Turn into `DROP INDEX IF EXISTS`.
+    pub fn if_exists(mut self) -> DropIndex<'a> {
+        self.if_exists = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::drop_index("book_title").if_exists().on("book");
+    assert_eq!(query.to_string(), "DROP INDEX IF EXISTS book_title");
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query
+            .clone()
+            .concurrently()
+            .to_sql::<sqlx::Postgres>()
+            .unwrap()
+            .0,
+        "DROP INDEX CONCURRENTLY IF EXISTS \"book_title\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.to_sql::<sqlx::Sqlite>().unwrap().0,
+        "DROP INDEX IF EXISTS \"book_title\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        crate::stmt::drop_index("book_title")
+            .on("book")
+            .to_sql::<sqlx::MySql>()
+            .unwrap()
+            .0,
+        "DROP INDEX `book_title` ON `book`"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(crate::stmt::drop_index("book_title")
+        .to_sql::<sqlx::MySql>()
+        .is_err());
+}
@@ -0,0 +1,60 @@
+use crate::item::Ident;
+
+/// `DROP SCHEMA` statement builder.
+///
+/// Sqlite has no schema besides attached databases, building it is an error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DropSchema<'a> {
+    pub(crate) name: Ident<'a>,
+    pub(crate) if_exists: bool,
+    pub(crate) cascade: bool,
+}
+
+crate::macros::gen_display!(DropSchema<'_>);
+
+impl<'a> std::convert::From<DropSchema<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: DropSchema<'a>) -> Self {
+        crate::stmt::Stmt::DropSchema(val)
+    }
+}
+
+impl<'a> DropSchema<'a> {
+    /// Turn into `DROP SCHEMA IF EXISTS`.
+    pub fn if_exists(mut self) -> DropSchema<'a> {
+        self.if_exists = true;
+        self
+    }
+
+    /// Also drop every object of the schema, postgres only. Mysql always
+    /// drops them.
+    pub fn cascade(mut self) -> DropSchema<'a> {
+        self.cascade = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::drop_schema("tenant_1").if_exists().cascade();
+    assert_eq!(query.to_string(), "DROP SCHEMA IF EXISTS tenant_1 CASCADE");
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "DROP SCHEMA IF EXISTS \"tenant_1\" CASCADE"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "DROP SCHEMA IF EXISTS `tenant_1`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
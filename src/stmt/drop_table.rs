@@ -0,0 +1,66 @@
+use crate::item::TableRef;
+
+/// `DROP TABLE` statement builder.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DropTable<'a> {
+    pub(crate) table: TableRef<'a>,
+    pub(crate) if_exists: bool,
+    pub(crate) cascade: bool,
+}
+
+crate::macros::gen_display!(DropTable<'_>);
+
+impl<'a> std::convert::From<DropTable<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: DropTable<'a>) -> Self {
+        crate::stmt::Stmt::DropTable(val)
+    }
+}
+
+impl<'a> DropTable<'a> {
+    /// Turn into `DROP TABLE IF EXISTS`.
+    pub fn if_exists(mut self) -> DropTable<'a> {
+        self.if_exists = true;
+        self
+    }
+
+    /// Also drop dependent objects, not available in sqlite.
+    pub fn cascade(mut self) -> DropTable<'a> {
+        self.cascade = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::drop_table("book").if_exists();
+    assert_eq!(query.to_string(), "DROP TABLE IF EXISTS book");
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+        "DROP TABLE IF EXISTS \"book\""
+    );
+
+    let query = query.cascade();
+    assert_eq!(query.to_string(), "DROP TABLE IF EXISTS book CASCADE");
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "DROP TABLE IF EXISTS \"book\" CASCADE"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "DROP TABLE IF EXISTS `book` CASCADE"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
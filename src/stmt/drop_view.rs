@@ -0,0 +1,63 @@
+use crate::item::TableRef;
+
+/// `DROP VIEW` statement builder.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DropView<'a> {
+    pub(crate) name: TableRef<'a>,
+    pub(crate) materialized: bool,
+    pub(crate) if_exists: bool,
+}
+
+crate::macros::gen_display!(DropView<'_>);
+
+impl<'a> std::convert::From<DropView<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: DropView<'a>) -> Self {
+        crate::stmt::Stmt::DropView(val)
+    }
+}
+
+impl<'a> DropView<'a> {
+    /// Turn into `DROP MATERIALIZED VIEW`, postgres only.
+    pub fn materialized(mut self) -> DropView<'a> {
+        self.materialized = true;
+        self
+    }
+
+    /// Turn into `DROP VIEW IF EXISTS`.
+    pub fn if_exists(mut self) -> DropView<'a> {
+        self.if_exists = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::drop_view("author_stats").if_exists();
+    assert_eq!(query.to_string(), "DROP VIEW IF EXISTS author_stats");
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "DROP VIEW IF EXISTS `author_stats`"
+    );
+
+    let query = query.materialized();
+    assert_eq!(
+        query.to_string(),
+        "DROP MATERIALIZED VIEW IF EXISTS author_stats"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "DROP MATERIALIZED VIEW IF EXISTS \"author_stats\""
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -0,0 +1,100 @@
+use crate::stmt::Stmt;
+
+/// `EXPLAIN` statement builder.
+///
+/// Sqlite renders `EXPLAIN QUERY PLAN` and supports none of the options,
+/// mysql supports `ANALYZE` and `FORMAT=JSON` but not both at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explain<'a> {
+    pub(crate) stmt: Box<Stmt<'a>>,
+    pub(crate) analyze: bool,
+    pub(crate) buffers: bool,
+    pub(crate) json: bool,
+}
+
+crate::macros::gen_display!(Explain<'_>);
+
+impl<'a> std::convert::From<Explain<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: Explain<'a>) -> Self {
+        crate::stmt::Stmt::Explain(val)
+    }
+}
+
+impl<'a> Explain<'a> {
+    /// Execute the statement and report actual timings.
+    pub fn analyze(mut self) -> Explain<'a> {
+        self.analyze = true;
+        self
+    }
+
+    /// Report buffer usage, postgres only.
+    pub fn buffers(mut self) -> Explain<'a> {
+        self.buffers = true;
+        self
+    }
+
+    /// `FORMAT JSON`, `FORMAT=JSON` on mysql.
+    pub fn format_json(mut self) -> Explain<'a> {
+        self.json = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ops::eq;
+
+    let query = crate::stmt::explain(crate::stmt::select(["id"]).from("book").filter(eq("id", 1)));
+    assert_eq!(
+        query.clone().analyze().buffers().format_json().to_string(),
+        "EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) SELECT id FROM book WHERE id = 1"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query
+            .clone()
+            .analyze()
+            .format_json()
+            .to_sql::<sqlx::Postgres>()
+            .unwrap(),
+        (
+            "EXPLAIN (ANALYZE, FORMAT JSON) SELECT \"id\" FROM \"book\" WHERE \"id\" = $1"
+                .to_string(),
+            vec![1.into()]
+        )
+    );
+
+    #[cfg(feature = "mysql")]
+    {
+        assert_eq!(
+            query
+                .clone()
+                .format_json()
+                .to_sql::<sqlx::MySql>()
+                .unwrap()
+                .0,
+            "EXPLAIN FORMAT=JSON SELECT `id` FROM `book` WHERE `id` = ?"
+        );
+        assert!(query
+            .clone()
+            .analyze()
+            .format_json()
+            .to_sql::<sqlx::MySql>()
+            .is_err());
+        assert!(query.clone().buffers().to_sql::<sqlx::MySql>().is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+            "EXPLAIN QUERY PLAN SELECT \"id\" FROM \"book\" WHERE \"id\" = ?"
+        );
+        assert!(query.analyze().to_sql::<sqlx::Sqlite>().is_err());
+    }
+}
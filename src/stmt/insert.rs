@@ -1,4 +1,5 @@
 use crate::clause;
+use crate::item::Ident;
 use crate::item::Row;
 
 /// `INSERT` statement builder.
@@ -7,6 +8,7 @@ pub struct Insert<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) table: clause::Insert<'a>,
     pub(crate) values: crate::stmt::data::Data<'a>,
+    pub(crate) conflict: Option<clause::OnConflict<'a>>,
     pub(crate) returns: Option<clause::Returning<'a>>,
 }
 
@@ -28,6 +30,17 @@ impl<'a> Insert<'a> {
         self
     }
 
+    /// Insert a single row made of column defaults.
+    ///
+    /// ```sql
+    /// INSERT INTO table DEFAULT VALUES
+    /// ```
+    pub fn default_values(mut self) -> Insert<'a> {
+        self.table.1.clear();
+        self.values = crate::stmt::values([Row(Vec::new())]).into();
+        self
+    }
+
     pub fn select<T>(mut self, select: T) -> Insert<'a>
     where
         T: Into<crate::stmt::select::Select<'a>>,
@@ -36,6 +49,51 @@ impl<'a> Insert<'a> {
         self
     }
 
+    /// Start an `ON CONFLICT (columns)` clause.
+    ///
+    /// On mysql this become `ON DUPLICATE KEY UPDATE` which checks any unique key.
+    pub fn on_conflict<I, C>(self, columns: I) -> Conflict<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        Conflict {
+            insert: self,
+            target: clause::ConflictTarget::Columns(columns.into_iter().map(Into::into).collect()),
+        }
+    }
+
+    /// Start an `ON CONFLICT ON CONSTRAINT name` clause.
+    pub fn on_constraint<N>(self, name: N) -> Conflict<'a>
+    where
+        N: Into<Ident<'a>>,
+    {
+        Conflict {
+            insert: self,
+            target: clause::ConflictTarget::Constraint(name.into()),
+        }
+    }
+
+    /// Skip conflicting rows, rendered as `INSERT IGNORE` on mysql,
+    /// `INSERT OR IGNORE` on sqlite and `ON CONFLICT DO NOTHING` elsewhere.
+    pub fn ignore(mut self) -> Insert<'a> {
+        self.conflict = Some(clause::OnConflict {
+            target: None,
+            action: clause::ConflictAction::Nothing,
+        });
+        self
+    }
+
+    /// Replace conflicting rows, rendered as `REPLACE` on mysql and
+    /// `INSERT OR REPLACE` on sqlite. Not supported on postgres.
+    pub fn replace(mut self) -> Insert<'a> {
+        self.conflict = Some(clause::OnConflict {
+            target: None,
+            action: clause::ConflictAction::Replace,
+        });
+        self
+    }
+
     pub fn returning<T>(mut self, returns: T) -> Insert<'a>
     where
         T: Into<clause::Returning<'a>>,
@@ -53,6 +111,48 @@ impl<'a> Insert<'a> {
 
 crate::macros::gen_display!(Insert<'_>);
 
+/// Pending `ON CONFLICT` clause of an [`Insert`] statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict<'a> {
+    insert: Insert<'a>,
+    target: clause::ConflictTarget<'a>,
+}
+
+impl<'a> Conflict<'a> {
+    /// Finish with `DO NOTHING`.
+    pub fn do_nothing(self) -> Insert<'a> {
+        self.finish(clause::ConflictAction::Nothing)
+    }
+
+    /// Finish with `DO UPDATE SET ...`.
+    pub fn do_update<S>(self, set: S) -> Insert<'a>
+    where
+        S: Into<clause::Set<'a>>,
+    {
+        self.finish(clause::ConflictAction::Update(set.into(), None))
+    }
+
+    /// Finish with `DO UPDATE SET ... WHERE ...`. Not supported on mysql.
+    pub fn do_update_where<S, W>(self, set: S, filter: W) -> Insert<'a>
+    where
+        S: Into<clause::Set<'a>>,
+        W: Into<clause::Where<'a>>,
+    {
+        self.finish(clause::ConflictAction::Update(
+            set.into(),
+            Some(filter.into()),
+        ))
+    }
+
+    fn finish(mut self, action: clause::ConflictAction<'a>) -> Insert<'a> {
+        self.insert.conflict = Some(clause::OnConflict {
+            target: Some(self.target),
+            action,
+        });
+        self.insert
+    }
+}
+
 #[test]
 #[cfg(test)]
 fn test() {
@@ -76,3 +176,165 @@ fn test() {
         "INSERT INTO user(id, name) SELECT 1, 'name' RETURNING id, name"
     );
 }
+
+#[test]
+#[cfg(test)]
+fn upsert() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ops::{eq, excluded};
+
+    let name = &"John".to_string();
+    let query = crate::stmt::insert("user", ["id", "name"]).values([(1, name)]);
+
+    assert_eq!(
+        query.clone().on_conflict(["id"]).do_nothing().to_string(),
+        "INSERT INTO user(id, name) VALUES (1, 'John') ON CONFLICT (id) DO NOTHING"
+    );
+    assert_eq!(
+        query
+            .clone()
+            .on_constraint("user_pkey")
+            .do_update_where([("name", excluded("name"))], eq(("user", "active"), true))
+            .to_string(),
+        "INSERT INTO user(id, name) VALUES (1, 'John') ON CONFLICT ON CONSTRAINT user_pkey DO UPDATE SET name = excluded.name WHERE user.active = true"
+    );
+    assert_eq!(
+        query.clone().ignore().to_string(),
+        "INSERT INTO user(id, name) VALUES (1, 'John') ON CONFLICT DO NOTHING"
+    );
+    assert_eq!(
+        query.clone().replace().to_string(),
+        "REPLACE INTO user(id, name) VALUES (1, 'John')"
+    );
+
+    #[cfg(feature = "postgres")]
+    {
+        let update = query
+            .clone()
+            .on_conflict(["id"])
+            .do_update([("name", excluded("name"))]);
+
+        assert_eq!(
+            update.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+            "INSERT INTO \"user\"(\"id\", \"name\") VALUES ($1, $2) ON CONFLICT (\"id\") DO UPDATE SET \"name\" = excluded.\"name\""
+        );
+        assert!(query.clone().replace().to_sql::<sqlx::Postgres>().is_err());
+    }
+
+    #[cfg(feature = "mysql")]
+    {
+        let update = query
+            .clone()
+            .on_conflict(["id"])
+            .do_update([("name", excluded("name"))]);
+
+        assert_eq!(
+            update.clone().to_sql::<sqlx::MySql>().unwrap().0,
+            "INSERT INTO `user`(`id`, `name`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"
+        );
+        assert_eq!(
+            query
+                .clone()
+                .on_conflict(["id"])
+                .do_nothing()
+                .to_sql::<sqlx::MySql>()
+                .unwrap()
+                .0,
+            "INSERT INTO `user`(`id`, `name`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `id` = `id`"
+        );
+        assert_eq!(
+            query.clone().ignore().to_sql::<sqlx::MySql>().unwrap().0,
+            "INSERT IGNORE INTO `user`(`id`, `name`) VALUES (?, ?)"
+        );
+        assert_eq!(
+            query.clone().replace().to_sql::<sqlx::MySql>().unwrap().0,
+            "REPLACE INTO `user`(`id`, `name`) VALUES (?, ?)"
+        );
+        assert_eq!(
+            query
+                .clone()
+                .on_conflict(["id"])
+                .do_update_where([("name", excluded("name"))], true)
+                .to_sql::<sqlx::MySql>(),
+            Err(crate::error::Error::Unsupported {
+                dialect: "mysql",
+                feature: "ON CONFLICT DO UPDATE WHERE",
+            })
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    {
+        let update = query
+            .clone()
+            .on_conflict(["id"])
+            .do_update([("name", excluded("name"))]);
+
+        assert_eq!(
+            update.to_sql::<sqlx::Sqlite>().unwrap().0,
+            "INSERT INTO \"user\"(\"id\", \"name\") VALUES (?, ?) ON CONFLICT (\"id\") DO UPDATE SET \"name\" = excluded.\"name\""
+        );
+        assert_eq!(
+            query.clone().ignore().to_sql::<sqlx::Sqlite>().unwrap().0,
+            "INSERT OR IGNORE INTO \"user\"(\"id\", \"name\") VALUES (?, ?)"
+        );
+        assert_eq!(
+            query.clone().replace().to_sql::<sqlx::Sqlite>().unwrap().0,
+            "INSERT OR REPLACE INTO \"user\"(\"id\", \"name\") VALUES (?, ?)"
+        );
+        assert!(query
+            .on_constraint("user_pkey")
+            .do_nothing()
+            .to_sql::<sqlx::Sqlite>()
+            .is_err());
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn default() {
+    #[cfg(any(feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::expr::Expr;
+
+    let john = &"John".to_string();
+    let jack = &"Jack".to_string();
+    let query = crate::stmt::insert("user", ["id", "name", "created"]).values([
+        (Expr::Default, john, Expr::Default),
+        (Expr::Default, jack, Expr::Default),
+    ]);
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO user(id, name, created) VALUES (DEFAULT, 'John', DEFAULT), (DEFAULT, 'Jack', DEFAULT)"
+    );
+
+    let defaults = crate::stmt::insert("user", ["id"]).default_values();
+    assert_eq!(defaults.to_string(), "INSERT INTO user DEFAULT VALUES");
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        defaults.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "INSERT INTO `user` VALUES ()"
+    );
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert_eq!(
+            query.to_sql::<sqlx::Sqlite>().unwrap().0,
+            "INSERT INTO \"user\"(\"name\") VALUES (?), (?)"
+        );
+        assert_eq!(
+            crate::stmt::insert("user", ["id"])
+                .values([(Expr::Default,)])
+                .to_sql::<sqlx::Sqlite>()
+                .unwrap()
+                .0,
+            "INSERT INTO \"user\" DEFAULT VALUES"
+        );
+        assert!(crate::stmt::insert("user", ["id"])
+            .values([(Expr::Default,), (1.into(),)])
+            .to_sql::<sqlx::Sqlite>()
+            .is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::clause;
+use crate::expr::Expr;
+use crate::item::Ident;
+use crate::item::Row;
+use crate::item::Table;
+
+/// `MERGE` statement builder.
+///
+/// Only postgres (15+) has `MERGE`, building it for mysql or sqlite is an
+/// error, as is building it without any `WHEN` arm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Merge<'a> {
+    pub(crate) with: Option<clause::With<'a>>,
+    pub(crate) target: Table<'a>,
+    pub(crate) source: Table<'a>,
+    pub(crate) cond: Expr<'a>,
+    pub(crate) arms: Vec<clause::MergeWhen<'a>>,
+}
+
+stmt_common!(Merge);
+
+crate::macros::gen_display!(Merge<'_>);
+
+impl<'a> Merge<'a> {
+    /// Start a `WHEN MATCHED` arm.
+    pub fn when_matched(self) -> Matched<'a> {
+        Matched {
+            merge: self,
+            cond: None,
+        }
+    }
+
+    /// Start a `WHEN MATCHED AND cond` arm.
+    pub fn when_matched_and<E>(self, cond: E) -> Matched<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        Matched {
+            merge: self,
+            cond: Some(cond.into()),
+        }
+    }
+
+    /// Start a `WHEN NOT MATCHED` arm.
+    pub fn when_not_matched(self) -> NotMatched<'a> {
+        NotMatched {
+            merge: self,
+            cond: None,
+        }
+    }
+
+    /// Start a `WHEN NOT MATCHED AND cond` arm.
+    pub fn when_not_matched_and<E>(self, cond: E) -> NotMatched<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        NotMatched {
+            merge: self,
+            cond: Some(cond.into()),
+        }
+    }
+
+    fn arm(
+        mut self,
+        matched: bool,
+        cond: Option<Expr<'a>>,
+        action: clause::MergeAction<'a>,
+    ) -> Merge<'a> {
+        self.arms.push(clause::MergeWhen {
+            matched,
+            cond,
+            action,
+        });
+        self
+    }
+}
+
+/// Pending `WHEN MATCHED` arm of a [`Merge`] statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matched<'a> {
+    merge: Merge<'a>,
+    cond: Option<Expr<'a>>,
+}
+
+impl<'a> Matched<'a> {
+    /// Finish with `THEN UPDATE SET ...`.
+    pub fn update<S>(self, set: S) -> Merge<'a>
+    where
+        S: Into<clause::Set<'a>>,
+    {
+        self.merge
+            .arm(true, self.cond, clause::MergeAction::Update(set.into()))
+    }
+
+    /// Finish with `THEN DELETE`.
+    pub fn delete(self) -> Merge<'a> {
+        self.merge.arm(true, self.cond, clause::MergeAction::Delete)
+    }
+
+    /// Finish with `THEN DO NOTHING`.
+    pub fn do_nothing(self) -> Merge<'a> {
+        self.merge
+            .arm(true, self.cond, clause::MergeAction::Nothing)
+    }
+}
+
+/// Pending `WHEN NOT MATCHED` arm of a [`Merge`] statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotMatched<'a> {
+    merge: Merge<'a>,
+    cond: Option<Expr<'a>>,
+}
+
+impl<'a> NotMatched<'a> {
+    /// Finish with `THEN INSERT (columns) VALUES (values)`.
+    pub fn insert<I, C, R>(self, columns: I, values: R) -> Merge<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+        R: Into<Row<'a>>,
+    {
+        let columns = columns.into_iter().map(Into::into).collect();
+        self.merge.arm(
+            false,
+            self.cond,
+            clause::MergeAction::Insert(columns, values.into()),
+        )
+    }
+
+    /// Finish with `THEN INSERT DEFAULT VALUES`.
+    pub fn insert_default(self) -> Merge<'a> {
+        self.merge.arm(
+            false,
+            self.cond,
+            clause::MergeAction::Insert(Vec::new(), Row::default()),
+        )
+    }
+
+    /// Finish with `THEN DO NOTHING`.
+    pub fn do_nothing(self) -> Merge<'a> {
+        self.merge
+            .arm(false, self.cond, clause::MergeAction::Nothing)
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ops::{as_table, eq, gt};
+
+    let query = crate::stmt::merge(
+        as_table("stock", "s"),
+        as_table("delivery", "d"),
+        eq(("s", "item"), ("d", "item")),
+    )
+    .when_matched_and(eq(("d", "qty"), 0))
+    .delete()
+    .when_matched()
+    .update([("qty", crate::ops::add(("s", "qty"), ("d", "qty")))])
+    .when_not_matched_and(gt(("d", "qty"), 0))
+    .insert(["item", "qty"], (("d", "item"), ("d", "qty")))
+    .when_not_matched()
+    .do_nothing();
+    assert_eq!(
+        query.to_string(),
+        "MERGE INTO stock AS s USING delivery AS d ON s.item = d.item \
+         WHEN MATCHED AND d.qty = 0 THEN DELETE \
+         WHEN MATCHED THEN UPDATE SET qty = s.qty + d.qty \
+         WHEN NOT MATCHED AND d.qty > 0 THEN INSERT (item, qty) VALUES (d.item, d.qty) \
+         WHEN NOT MATCHED THEN DO NOTHING"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "MERGE INTO \"stock\" AS \"s\" USING \"delivery\" AS \"d\" ON \"s\".\"item\" = \"d\".\"item\" \
+         WHEN MATCHED AND \"d\".\"qty\" = $1 THEN DELETE \
+         WHEN MATCHED THEN UPDATE SET \"qty\" = \"s\".\"qty\" + \"d\".\"qty\" \
+         WHEN NOT MATCHED AND \"d\".\"qty\" > $2 THEN INSERT (\"item\", \"qty\") VALUES (\"d\".\"item\", \"d\".\"qty\") \
+         WHEN NOT MATCHED THEN DO NOTHING"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+    let query = crate::stmt::merge("log", "incoming", eq(("log", "id"), ("incoming", "id")))
+        .when_not_matched()
+        .insert_default();
+    assert_eq!(
+        query.to_string(),
+        "MERGE INTO log USING incoming ON log.id = incoming.id WHEN NOT MATCHED THEN INSERT DEFAULT VALUES"
+    );
+
+    let query = crate::stmt::merge("log", "incoming", eq(("log", "id"), ("incoming", "id")));
+    assert!(matches!(
+        crate::build::ToSql::to_sql::<crate::build::Display>(query.clone()),
+        Err(crate::error::Error::Invalid { .. })
+    ));
+    let query = query
+        .when_not_matched()
+        .insert(["id", "at"], (("incoming", "id"),));
+    assert!(matches!(
+        crate::build::ToSql::to_sql::<crate::build::Display>(query),
+        Err(crate::error::Error::Invalid { .. })
+    ));
+}
@@ -1,6 +1,7 @@
 use crate::clause;
 use crate::item::Ident;
 use crate::item::Row;
+use crate::item::Table;
 use crate::item::TableRef;
 
 macro_rules! stmt_common {
@@ -29,6 +30,9 @@ macro_rules! stmt_common {
                             name: name.into(),
                             columns: Vec::new(),
                             stmt: stmt.into(),
+                            materialized: None,
+                            search: None,
+                            cycle: None,
                         });
                         Some(with)
                     }
@@ -37,6 +41,9 @@ macro_rules! stmt_common {
                             name: name.into(),
                             columns: Vec::new(),
                             stmt: stmt.into(),
+                            materialized: None,
+                            search: None,
+                            cycle: None,
                         }]
                         .into(),
                     ),
@@ -62,6 +69,9 @@ macro_rules! stmt_common {
                             name: name.into(),
                             columns: fields.into_iter().map(Into::into).collect(),
                             stmt: stmt.into(),
+                            materialized: None,
+                            search: None,
+                            cycle: None,
                         });
                         Some(with)
                     }
@@ -70,6 +80,9 @@ macro_rules! stmt_common {
                             name: name.into(),
                             columns: fields.into_iter().map(Into::into).collect(),
                             stmt: stmt.into(),
+                            materialized: None,
+                            search: None,
+                            cycle: None,
                         }]
                         .into(),
                     ),
@@ -77,6 +90,121 @@ macro_rules! stmt_common {
                 self
             }
 
+            /// Force the last added [`Cte`](crate::item::Cte) to be computed
+            /// once, not supported by mysql.
+            ///
+            /// ```sql
+            /// WITH name AS MATERIALIZED (stmt) ...
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Without a previous [`with`](Self::with) or
+            /// [`with_labeled`](Self::with_labeled).
+            pub fn materialized(self) -> $stmt<'a> {
+                self.last_cte(|cte| cte.materialized = Some(true))
+            }
+
+            /// Allow the last added [`Cte`](crate::item::Cte) to be inlined
+            /// into the query, not supported by mysql.
+            ///
+            /// ```sql
+            /// WITH name AS NOT MATERIALIZED (stmt) ...
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Without a previous [`with`](Self::with) or
+            /// [`with_labeled`](Self::with_labeled).
+            pub fn not_materialized(self) -> $stmt<'a> {
+                self.last_cte(|cte| cte.materialized = Some(false))
+            }
+
+            /// Order the rows of the last added recursive
+            /// [`Cte`](crate::item::Cte) depth first, postgres only.
+            ///
+            /// ```sql
+            /// WITH RECURSIVE name AS (stmt) SEARCH DEPTH FIRST BY columns SET set ...
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Without a previous [`with`](Self::with) or
+            /// [`with_labeled`](Self::with_labeled).
+            pub fn search_depth_first<C, I, S>(self, columns: I, set: S) -> $stmt<'a>
+            where
+                C: Into<$crate::item::Ident<'a>>,
+                I: IntoIterator<Item = C>,
+                S: Into<$crate::item::Ident<'a>>,
+            {
+                let search = $crate::item::Search {
+                    breadth: false,
+                    by: columns.into_iter().map(Into::into).collect(),
+                    set: set.into(),
+                };
+                self.last_cte(|cte| cte.search = Some(search))
+            }
+
+            /// Order the rows of the last added recursive
+            /// [`Cte`](crate::item::Cte) breadth first, postgres only.
+            ///
+            /// ```sql
+            /// WITH RECURSIVE name AS (stmt) SEARCH BREADTH FIRST BY columns SET set ...
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Without a previous [`with`](Self::with) or
+            /// [`with_labeled`](Self::with_labeled).
+            pub fn search_breadth_first<C, I, S>(self, columns: I, set: S) -> $stmt<'a>
+            where
+                C: Into<$crate::item::Ident<'a>>,
+                I: IntoIterator<Item = C>,
+                S: Into<$crate::item::Ident<'a>>,
+            {
+                let search = $crate::item::Search {
+                    breadth: true,
+                    by: columns.into_iter().map(Into::into).collect(),
+                    set: set.into(),
+                };
+                self.last_cte(|cte| cte.search = Some(search))
+            }
+
+            /// Detect cycles in the last added recursive
+            /// [`Cte`](crate::item::Cte), postgres only.
+            ///
+            /// ```sql
+            /// WITH RECURSIVE name AS (stmt) CYCLE columns SET set USING using ...
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Without a previous [`with`](Self::with) or
+            /// [`with_labeled`](Self::with_labeled).
+            pub fn cycle<C, I, S, U>(self, columns: I, set: S, using: U) -> $stmt<'a>
+            where
+                C: Into<$crate::item::Ident<'a>>,
+                I: IntoIterator<Item = C>,
+                S: Into<$crate::item::Ident<'a>>,
+                U: Into<$crate::item::Ident<'a>>,
+            {
+                let cycle = $crate::item::Cycle {
+                    columns: columns.into_iter().map(Into::into).collect(),
+                    set: set.into(),
+                    using: using.into(),
+                };
+                self.last_cte(|cte| cte.cycle = Some(cycle))
+            }
+
+            fn last_cte<F>(mut self, f: F) -> $stmt<'a>
+            where
+                F: FnOnce(&mut $crate::item::Cte<'a>),
+            {
+                let cte = self.with.as_mut().and_then(|with| with.1.last_mut());
+                f(cte.expect("no CTE added by `with` or `with_labeled`"));
+                self
+            }
+
             /// Turns the [`With`](crate::clause::With) into recursive.
             ///
             /// ```sql
@@ -118,12 +246,28 @@ macro_rules! stmt_common {
     };
 }
 
+pub mod alter_enum;
+pub mod alter_table;
 pub mod binary;
+pub mod create_enum;
+pub mod create_index;
+pub mod create_schema;
+pub mod create_sequence;
+pub mod create_table;
+pub mod create_view;
 pub mod data;
 pub mod delete;
+pub mod drop_index;
+pub mod drop_schema;
+pub mod drop_table;
+pub mod drop_view;
+pub mod explain;
 pub mod insert;
+pub mod merge;
+pub mod refresh_view;
 pub mod result;
 pub mod select;
+pub mod truncate;
 pub mod update;
 pub mod values;
 
@@ -133,6 +277,22 @@ pub enum Stmt<'a> {
     Select(select::Select<'a>),
     Update(update::Update<'a>),
     Delete(delete::Delete<'a>),
+    Merge(merge::Merge<'a>),
+    CreateTable(create_table::CreateTable<'a>),
+    AlterTable(alter_table::AlterTable<'a>),
+    CreateIndex(create_index::CreateIndex<'a>),
+    DropIndex(drop_index::DropIndex<'a>),
+    CreateView(create_view::CreateView<'a>),
+    DropView(drop_view::DropView<'a>),
+    RefreshView(refresh_view::RefreshView<'a>),
+    DropTable(drop_table::DropTable<'a>),
+    Truncate(truncate::Truncate<'a>),
+    CreateSchema(create_schema::CreateSchema<'a>),
+    DropSchema(drop_schema::DropSchema<'a>),
+    CreateEnum(create_enum::CreateEnum<'a>),
+    AlterEnum(alter_enum::AlterEnum<'a>),
+    CreateSequence(create_sequence::CreateSequence<'a>),
+    Explain(explain::Explain<'a>),
     Values(values::Values<'a>),
     Binary(binary::Binary<'a>),
     Result(result::Result<'a>),
@@ -275,6 +435,404 @@ where
     }
 }
 
+/// Construct a `MERGE` statement, merging rows of `source` into `target`
+/// matched by `cond`.
+///
+/// # Examples
+///
+/// ```
+/// use xql::eq;
+/// use xql::merge;
+///
+/// assert_eq!(
+///     merge("book", "draft", eq(("book", "id"), ("draft", "id")))
+///         .when_matched()
+///         .update([("title", ("draft", "title"))])
+///         .when_not_matched()
+///         .insert(["id", "title"], (("draft", "id"), ("draft", "title")))
+///         .to_string(),
+///     "MERGE INTO book USING draft ON book.id = draft.id \
+///      WHEN MATCHED THEN UPDATE SET title = draft.title \
+///      WHEN NOT MATCHED THEN INSERT (id, title) VALUES (draft.id, draft.title)",
+/// );
+/// ```
+#[inline]
+pub fn merge<'a, T, S, E>(target: T, source: S, cond: E) -> merge::Merge<'a>
+where
+    T: Into<Table<'a>>,
+    S: Into<Table<'a>>,
+    E: Into<crate::expr::Expr<'a>>,
+{
+    merge::Merge {
+        with: None,
+        target: target.into(),
+        source: source.into(),
+        cond: cond.into(),
+        arms: Vec::new(),
+    }
+}
+
+/// Construct a `CREATE TABLE` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::create_table;
+/// use xql::ddl::{column, Type};
+///
+/// assert_eq!(
+///     create_table("book")
+///         .column(column("id", Type::Int).primary_key())
+///         .column(column("title", Type::Text).not_null())
+///         .to_string(),
+///     "CREATE TABLE book (id INTEGER PRIMARY KEY, title TEXT NOT NULL)",
+/// );
+/// ```
+#[inline]
+pub fn create_table<'a, T>(table: T) -> create_table::CreateTable<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    create_table::CreateTable {
+        table: table.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct an `ALTER TABLE` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::alter_table;
+/// use xql::ddl::{column, Type};
+///
+/// assert_eq!(
+///     alter_table("book")
+///         .add_column(column("isbn", Type::Varchar(13)))
+///         .drop_column("price")
+///         .to_string(),
+///     "ALTER TABLE book ADD COLUMN isbn VARCHAR(13), DROP COLUMN price",
+/// );
+/// ```
+#[inline]
+pub fn alter_table<'a, T>(table: T) -> alter_table::AlterTable<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    alter_table::AlterTable {
+        table: table.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `CREATE INDEX` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::create_index;
+///
+/// assert_eq!(
+///     create_index("book_author").on("book", ["author_id"]).to_string(),
+///     "CREATE INDEX book_author ON book (author_id)",
+/// );
+/// ```
+#[inline]
+pub fn create_index<'a, N>(name: N) -> create_index::CreateIndex<'a>
+where
+    N: Into<Ident<'a>>,
+{
+    create_index::CreateIndex {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `DROP INDEX` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::drop_index;
+///
+/// assert_eq!(
+///     drop_index("book_author").if_exists().to_string(),
+///     "DROP INDEX IF EXISTS book_author",
+/// );
+/// ```
+#[inline]
+pub fn drop_index<'a, N>(name: N) -> drop_index::DropIndex<'a>
+where
+    N: Into<Ident<'a>>,
+{
+    drop_index::DropIndex {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `CREATE VIEW` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::create_view;
+/// use xql::select;
+///
+/// assert_eq!(
+///     create_view("cheap_book", select(["id"]).from("book").filter(xql::lt("price", 10))).to_string(),
+///     "CREATE VIEW cheap_book AS SELECT id FROM book WHERE price < 10",
+/// );
+/// ```
+#[inline]
+pub fn create_view<'a, N, Q>(name: N, query: Q) -> create_view::CreateView<'a>
+where
+    N: Into<TableRef<'a>>,
+    Q: Into<data::Data<'a>>,
+{
+    create_view::CreateView {
+        name: name.into(),
+        query: query.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `DROP VIEW` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::drop_view;
+///
+/// assert_eq!(
+///     drop_view("cheap_book").if_exists().to_string(),
+///     "DROP VIEW IF EXISTS cheap_book",
+/// );
+/// ```
+#[inline]
+pub fn drop_view<'a, N>(name: N) -> drop_view::DropView<'a>
+where
+    N: Into<TableRef<'a>>,
+{
+    drop_view::DropView {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `REFRESH MATERIALIZED VIEW` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::refresh_view;
+///
+/// assert_eq!(
+///     refresh_view("book_count").to_string(),
+///     "REFRESH MATERIALIZED VIEW book_count",
+/// );
+/// ```
+#[inline]
+pub fn refresh_view<'a, N>(name: N) -> refresh_view::RefreshView<'a>
+where
+    N: Into<TableRef<'a>>,
+{
+    refresh_view::RefreshView {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `DROP TABLE` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::drop_table;
+///
+/// assert_eq!(
+///     drop_table("book").if_exists().cascade().to_string(),
+///     "DROP TABLE IF EXISTS book CASCADE",
+/// );
+/// ```
+#[inline]
+pub fn drop_table<'a, T>(table: T) -> drop_table::DropTable<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    drop_table::DropTable {
+        table: table.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `TRUNCATE` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::truncate;
+///
+/// assert_eq!(
+///     truncate("book").restart_identity().to_string(),
+///     "TRUNCATE book RESTART IDENTITY",
+/// );
+/// ```
+#[inline]
+pub fn truncate<'a, T>(table: T) -> truncate::Truncate<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    truncate::Truncate {
+        table: table.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `CREATE SCHEMA` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::create_schema;
+///
+/// assert_eq!(
+///     create_schema("tenant_1").if_not_exists().to_string(),
+///     "CREATE SCHEMA IF NOT EXISTS tenant_1",
+/// );
+/// ```
+#[inline]
+pub fn create_schema<'a, N>(name: N) -> create_schema::CreateSchema<'a>
+where
+    N: Into<Ident<'a>>,
+{
+    create_schema::CreateSchema {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `DROP SCHEMA` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::drop_schema;
+///
+/// assert_eq!(
+///     drop_schema("tenant_1").if_exists().cascade().to_string(),
+///     "DROP SCHEMA IF EXISTS tenant_1 CASCADE",
+/// );
+/// ```
+#[inline]
+pub fn drop_schema<'a, N>(name: N) -> drop_schema::DropSchema<'a>
+where
+    N: Into<Ident<'a>>,
+{
+    drop_schema::DropSchema {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct a `CREATE TYPE name AS ENUM (..)` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::create_enum;
+///
+/// assert_eq!(
+///     create_enum("status", ["draft", "published"]).to_string(),
+///     "CREATE TYPE status AS ENUM ('draft', 'published')",
+/// );
+/// ```
+#[inline]
+pub fn create_enum<'a, T, I>(name: T, values: I) -> create_enum::CreateEnum<'a>
+where
+    T: Into<TableRef<'a>>,
+    I: IntoIterator<Item = &'a str>,
+{
+    create_enum::CreateEnum {
+        name: name.into(),
+        values: values.into_iter().collect(),
+    }
+}
+
+/// Construct an `ALTER TYPE name ADD VALUE value` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::alter_enum;
+///
+/// assert_eq!(
+///     alter_enum("status", "archived").before("published").to_string(),
+///     "ALTER TYPE status ADD VALUE 'archived' BEFORE 'published'",
+/// );
+/// ```
+#[inline]
+pub fn alter_enum<'a, T>(name: T, value: &'a str) -> alter_enum::AlterEnum<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    alter_enum::AlterEnum {
+        name: name.into(),
+        value,
+        ..Default::default()
+    }
+}
+
+/// Construct a `CREATE SEQUENCE` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::create_sequence;
+///
+/// assert_eq!(
+///     create_sequence("ticket_seq").start(100).to_string(),
+///     "CREATE SEQUENCE ticket_seq START WITH 100",
+/// );
+/// ```
+#[inline]
+pub fn create_sequence<'a, T>(name: T) -> create_sequence::CreateSequence<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    create_sequence::CreateSequence {
+        name: name.into(),
+        ..Default::default()
+    }
+}
+
+/// Construct an `EXPLAIN` statement.
+///
+/// # Examples
+///
+/// ```
+/// use xql::explain;
+/// use xql::select;
+///
+/// assert_eq!(
+///     explain(select(["id"]).from("book")).analyze().to_string(),
+///     "EXPLAIN (ANALYZE) SELECT id FROM book",
+/// );
+/// ```
+#[inline]
+pub fn explain<'a, S>(stmt: S) -> explain::Explain<'a>
+where
+    S: Into<Stmt<'a>>,
+{
+    explain::Explain {
+        stmt: Box::new(stmt.into()),
+        analyze: false,
+        buffers: false,
+        json: false,
+    }
+}
+
 macro_rules! generate_binary_funcs {
     ($(#[$comment:meta])* $fn:ident $op:expr) => {
         $(#[$comment])*
@@ -289,6 +847,7 @@ macro_rules! generate_binary_funcs {
                 op: $op,
                 left: ::std::boxed::Box::new(left.into()),
                 right: ::std::boxed::Box::new(right.into()),
+                orders: None,
             }
         }
     };
@@ -404,4 +963,75 @@ mod tests {
             "WITH tbl1(name) AS (VALUES ('tbl1')), tbl2 AS (SELECT 'tbl2' AS name) SELECT name FROM tbl1, tbl2"
         );
     }
+
+    #[test]
+    fn cte_options() {
+        #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+        use crate::build::ToSql;
+        use crate::ops::{as_table, eq, join};
+
+        let tree = crate::stmt::union_all(
+            crate::stmt::select([("t", "id"), ("t", "parent")]).from(as_table("tree", "t")),
+            crate::stmt::select([("c", "id"), ("c", "parent")]).from(join(
+                as_table("tree", "c"),
+                "walk",
+                eq(("c", "parent"), ("walk", "id")),
+            )),
+        );
+        let query = crate::stmt::select(["id"])
+            .from("walk")
+            .with_labeled("walk", ["id", "parent"], tree)
+            .recursive()
+            .search_depth_first(["id"], "ord")
+            .cycle(["id"], "is_cycle", "path")
+            .with("roots", crate::stmt::select(["id"]).from("tree"))
+            .not_materialized();
+        assert_eq!(
+            query.to_string(),
+            "WITH RECURSIVE walk(id, parent) AS (SELECT t.id, t.parent FROM tree AS t UNION ALL SELECT c.id, c.parent FROM tree AS c \
+             JOIN walk ON c.parent = walk.id) SEARCH DEPTH FIRST BY id SET ord CYCLE id SET is_cycle USING path, \
+             roots AS NOT MATERIALIZED (SELECT id FROM tree) SELECT id FROM walk"
+        );
+
+        #[cfg(feature = "postgres")]
+        assert!(query
+            .clone()
+            .to_sql::<sqlx::Postgres>()
+            .unwrap()
+            .0
+            .contains("\"roots\" AS NOT MATERIALIZED (SELECT \"id\" FROM \"tree\")"));
+
+        #[cfg(feature = "mysql")]
+        assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+        #[cfg(feature = "sqlite")]
+        assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+        let query = crate::stmt::select(["id"])
+            .from("book")
+            .with("book", crate::stmt::select(["id"]).from("books"))
+            .materialized()
+            .search_breadth_first(["id"], "ord");
+        assert_eq!(
+            query.to_string(),
+            "/* invalid statement: SEARCH or CYCLE outside of WITH RECURSIVE */"
+        );
+
+        #[cfg(feature = "postgres")]
+        assert!(matches!(
+            query.clone().to_sql::<sqlx::Postgres>(),
+            Err(crate::error::Error::Invalid { .. })
+        ));
+
+        assert_eq!(
+            query.recursive().to_string(),
+            "WITH RECURSIVE book AS MATERIALIZED (SELECT id FROM books) SEARCH BREADTH FIRST BY id SET ord SELECT id FROM book"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no CTE added")]
+    fn cte_options_without_cte() {
+        crate::stmt::select(["id"]).from("book").materialized();
+    }
 }
@@ -0,0 +1,50 @@
+use crate::item::TableRef;
+
+/// `REFRESH MATERIALIZED VIEW` statement builder, postgres only.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RefreshView<'a> {
+    pub(crate) name: TableRef<'a>,
+    pub(crate) concurrently: bool,
+}
+
+crate::macros::gen_display!(RefreshView<'_>);
+
+impl<'a> std::convert::From<RefreshView<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: RefreshView<'a>) -> Self {
+        crate::stmt::Stmt::RefreshView(val)
+    }
+}
+
+impl<'a> RefreshView<'a> {
+    /// Refresh without locking out readers, the view needs a unique index.
+    pub fn concurrently(mut self) -> RefreshView<'a> {
+        self.concurrently = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::refresh_view("book_count").concurrently();
+    assert_eq!(
+        query.to_string(),
+        "REFRESH MATERIALIZED VIEW CONCURRENTLY book_count"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "REFRESH MATERIALIZED VIEW CONCURRENTLY \"book_count\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -6,6 +6,8 @@ pub struct Result<'a> {
     pub(crate) data: crate::stmt::data::Data<'a>,
     pub(crate) limit: Option<clause::Limit>,
     pub(crate) offset: Option<clause::Offset>,
+    pub(crate) fetch: Option<clause::Fetch>,
+    pub(crate) lock: Option<clause::Lock<'a>>,
 }
 
 stmt_common!(Result);
@@ -25,6 +27,61 @@ where
     }
 }
 
+impl<'a> Result<'a> {
+    /// Set row locking clause, rendered after `LIMIT` and `OFFSET`.
+    ///
+    /// Overrides the locking clause of the inner `SELECT`, if any.
+    pub fn lock<L>(mut self, lock: L) -> Result<'a>
+    where
+        L: Into<clause::Lock<'a>>,
+    {
+        self.lock = Some(lock.into());
+        self
+    }
+
+    /// Set `LIMIT` clause, replacing `FETCH FIRST` if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("book").offset(20).limit(10).to_string(),
+    ///     "SELECT id FROM book LIMIT 10 OFFSET 20",
+    /// );
+    /// ```
+    pub fn limit(mut self, limit: u32) -> Result<'a> {
+        self.limit = Some(clause::Limit(limit));
+        self.fetch = None;
+        self
+    }
+
+    /// Set `OFFSET` clause.
+    pub fn offset(mut self, offset: u32) -> Result<'a> {
+        self.offset = Some(clause::Offset(offset));
+        self
+    }
+
+    /// Set `FETCH FIRST n ROWS ONLY` clause, replacing `LIMIT` if any.
+    ///
+    /// Rendered as `LIMIT` on mysql and sqlite.
+    pub fn fetch_first(mut self, count: u32) -> Result<'a> {
+        self.fetch = Some(clause::Fetch(count, false));
+        self.limit = None;
+        self
+    }
+
+    /// Set `FETCH FIRST n ROWS WITH TIES` clause, replacing `LIMIT` if any.
+    ///
+    /// Not supported on mysql and sqlite.
+    pub fn fetch_first_with_ties(mut self, count: u32) -> Result<'a> {
+        self.fetch = Some(clause::Fetch(count, true));
+        self.limit = None;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::stmt::select;
@@ -45,4 +102,80 @@ mod tests {
         let query = values([(1,), (2,)]).offset(10);
         assert_eq!(query.to_string(), "VALUES (1), (2) OFFSET 10");
     }
+
+    #[test]
+    fn fetch() {
+        let query = select(["id"])
+            .from("data")
+            .order_by(["id"])
+            .offset(10)
+            .fetch_first_with_ties(5);
+        assert_eq!(
+            query.to_string(),
+            "SELECT id FROM data ORDER BY id OFFSET 10 FETCH FIRST 5 ROWS WITH TIES"
+        );
+        let query = select(["id"]).from("data").limit(5).fetch_first(10);
+        assert_eq!(
+            query.to_string(),
+            "SELECT id FROM data FETCH FIRST 10 ROWS ONLY"
+        );
+    }
+
+    #[test]
+    fn lock() {
+        use crate::clause::Lock;
+
+        let query = select(["id"])
+            .from("data")
+            .lock(Lock::share())
+            .limit(1)
+            .lock(Lock::update().nowait());
+        assert_eq!(
+            query.to_string(),
+            "SELECT id FROM data LIMIT 1 FOR UPDATE NOWAIT"
+        );
+    }
+
+    #[test]
+    fn bind() {
+        use crate::build::ToSql;
+
+        let (sql, args) = select(["id"])
+            .from("data")
+            .limit(10)
+            .offset(20)
+            .to_sql::<crate::build::Display>()
+            .unwrap();
+        assert_eq!(sql, "SELECT id FROM data LIMIT 10 OFFSET 20");
+        assert_eq!(
+            args,
+            [
+                crate::value::Value::BigInt(10),
+                crate::value::Value::BigInt(20)
+            ]
+        );
+
+        #[cfg(feature = "sqlite")]
+        assert_eq!(
+            select(["id"])
+                .from("data")
+                .offset(20)
+                .to_sql::<sqlx::Sqlite>()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"data\" LIMIT -1 OFFSET ?"
+        );
+
+        #[cfg(feature = "mysql")]
+        assert_eq!(
+            select(["id"])
+                .from("data")
+                .offset(20)
+                .fetch_first(10)
+                .to_sql::<sqlx::MySql>()
+                .unwrap()
+                .0,
+            "SELECT `id` FROM `data` LIMIT ? OFFSET ?"
+        );
+    }
 }
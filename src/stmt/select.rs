@@ -7,12 +7,15 @@ use crate::stmt::result::Result;
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Select<'a> {
     pub(crate) with: Option<clause::With<'a>>,
+    pub(crate) distinct: Option<clause::Distinct<'a>>,
     pub(crate) fields: clause::Select<'a>,
     pub(crate) tables: Option<clause::From<'a>>,
     pub(crate) filter: Option<clause::Where<'a>>,
     pub(crate) groups: Option<clause::GroupBy<'a>>,
     pub(crate) having: Option<clause::Having<'a>>,
+    pub(crate) windows: Option<clause::Window<'a>>,
     pub(crate) orders: Option<clause::OrderBy<'a>>,
+    pub(crate) lock: Option<clause::Lock<'a>>,
 }
 
 stmt_common!(Select);
@@ -42,6 +45,60 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Turn into `SELECT DISTINCT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["author"]).distinct().from("book").to_string(),
+    ///     "SELECT DISTINCT author FROM book",
+    /// );
+    /// ```
+    pub fn distinct(mut self) -> Select<'a> {
+        if self.distinct.is_none() {
+            self.distinct = Some(Default::default());
+        }
+        self
+    }
+
+    /// Turn into `SELECT DISTINCT ON (exprs)`, keeping the first row of each
+    /// group according to `ORDER BY`.
+    ///
+    /// Mysql and sqlite lack `DISTINCT ON`, so it is emulated there with a
+    /// `ROW_NUMBER()` subquery. Every field must be a column or have an alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::select;
+    /// use xql::desc;
+    ///
+    /// assert_eq!(
+    ///     select(["author", "title"])
+    ///         .distinct_on(["author"])
+    ///         .from("book")
+    ///         .order_by(["author".into(), desc("year")])
+    ///         .to_string(),
+    ///     "SELECT DISTINCT ON (author) author, title FROM book ORDER BY author, year DESC",
+    /// );
+    /// ```
+    pub fn distinct_on<D>(mut self, exprs: D) -> Select<'a>
+    where
+        D: Into<clause::Distinct<'a>>,
+    {
+        self.distinct = match self.distinct.take() {
+            Some(mut inner) => {
+                inner.0.extend(exprs.into().0);
+                Some(inner)
+            }
+            None => Some(exprs.into()),
+        };
+        self
+    }
+
     /// Add more table(s) to `FROM` clause.
     ///
     /// # Examples
@@ -175,6 +232,41 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Define a named window in `WINDOW` clause, to be referred by
+    /// [`FuncCall::over`](crate::item::FuncCall::over).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::item::{FrameBound, Window};
+    /// use xql::{as_field, func, select, sum};
+    ///
+    /// let query = select(["title"])
+    ///     .select([
+    ///         as_field(func("rank", Vec::<xql::expr::Expr>::new()).over("w"), "rank"),
+    ///         as_field(sum("sold").over(Window::from("w").rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow)), "total"),
+    ///     ])
+    ///     .from("book")
+    ///     .window("w", Window::default().partition_by(["author"]).order_by(["year"]));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT title, rank() OVER w AS rank, sum(sold) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS total \
+    ///      FROM book WINDOW w AS (PARTITION BY author ORDER BY year)",
+    /// );
+    /// ```
+    pub fn window<N, W>(mut self, name: N, spec: W) -> Select<'a>
+    where
+        N: Into<crate::item::Ident<'a>>,
+        W: Into<crate::item::Window<'a>>,
+    {
+        self.windows
+            .get_or_insert_with(Default::default)
+            .0
+            .push((name.into(), spec.into()));
+        self
+    }
+
     pub fn order_by<O>(mut self, orders: O) -> Select<'a>
     where
         O: Into<clause::OrderBy<'a>>,
@@ -189,6 +281,31 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Set row locking clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xql::select;
+    /// use xql::clause::Lock;
+    ///
+    /// assert_eq!(
+    ///     select(["id"])
+    ///         .from("job")
+    ///         .lock(Lock::update().skip_locked())
+    ///         .limit(1)
+    ///         .to_string(),
+    ///     "SELECT id FROM job LIMIT 1 FOR UPDATE SKIP LOCKED",
+    /// );
+    /// ```
+    pub fn lock<L>(mut self, lock: L) -> Select<'a>
+    where
+        L: Into<clause::Lock<'a>>,
+    {
+        self.lock = Some(lock.into());
+        self
+    }
+
     pub fn limit(self, limit: u32) -> Result<'a> {
         Result {
             data: self.into(),
@@ -204,6 +321,17 @@ impl<'a> Select<'a> {
             ..Default::default()
         }
     }
+
+    /// Add `FETCH FIRST n ROWS ONLY` clause, see [`Result::fetch_first`].
+    pub fn fetch_first(self, count: u32) -> Result<'a> {
+        Result::from(self).fetch_first(count)
+    }
+
+    /// Add `FETCH FIRST n ROWS WITH TIES` clause, see
+    /// [`Result::fetch_first_with_ties`].
+    pub fn fetch_first_with_ties(self, count: u32) -> Result<'a> {
+        Result::from(self).fetch_first_with_ties(count)
+    }
 }
 
 #[test]
@@ -235,3 +363,316 @@ fn test() {
     let expect = "SELECT data.id, data.value, count(id), max(age), min(age), avg(age) FROM public.data, unnest(data.value) WHERE data.id = 1 AND data.name = \'name\' GROUP BY data.id HAVING true ORDER BY data.id DESC";
     assert_eq!(query.to_string(), expect);
 }
+
+#[test]
+#[cfg(test)]
+fn distinct() {
+    #[cfg(any(feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ops;
+    use crate::stmt::select;
+
+    let query = select(["author"]).distinct().from("book");
+    assert_eq!(query.to_string(), "SELECT DISTINCT author FROM book");
+
+    let query = select(["author", "title"])
+        .distinct_on(["author"])
+        .from("book")
+        .filter(ops::gt("year", 1950))
+        .order_by(["author".into(), ops::desc("year")]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT DISTINCT ON (author) author, title FROM book WHERE year > 1950 ORDER BY author, year DESC"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+        "SELECT \"author\", \"title\" FROM (SELECT \"author\", \"title\", ROW_NUMBER() OVER (PARTITION BY \"author\" ORDER BY \"author\", \"year\" DESC) AS \"__rn\", \"author\" AS \"__o1\", \"year\" AS \"__o2\" FROM \"book\" WHERE \"year\" > ?) AS \"__distinct\" WHERE \"__rn\" = 1 ORDER BY \"__o1\", \"__o2\" DESC"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(select([crate::func::count("id")])
+        .distinct_on(["author"])
+        .from("book")
+        .to_sql::<sqlx::MySql>()
+        .is_err());
+}
+
+#[test]
+#[cfg(test)]
+fn lock() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::clause::Lock;
+    use crate::stmt::select;
+
+    let query = select(["id"])
+        .from("job")
+        .lock(Lock::share().of(["job"]).nowait());
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM job FOR SHARE OF job NOWAIT"
+    );
+
+    let query = select(["id"])
+        .from("job")
+        .order_by(["id"])
+        .lock(Lock::no_key_update().skip_locked())
+        .limit(10);
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM job ORDER BY id LIMIT 10 FOR NO KEY UPDATE SKIP LOCKED"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "SELECT \"id\" FROM \"job\" ORDER BY \"id\" LIMIT $1 FOR NO KEY UPDATE SKIP LOCKED"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "SELECT `id` FROM `job` ORDER BY `id` LIMIT ? FOR UPDATE SKIP LOCKED"
+    );
+
+    #[cfg(feature = "sqlite")]
+    {
+        assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+        assert_eq!(
+            select(["id"])
+                .from("job")
+                .lock(Lock::update().optional())
+                .to_sql::<sqlx::Sqlite>()
+                .unwrap()
+                .0,
+            "SELECT \"id\" FROM \"job\""
+        );
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn grouping() {
+    #[cfg(any(feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::func::count;
+    use crate::ops;
+    use crate::stmt::select;
+
+    let query = select(["year", "lang"])
+        .select([ops::as_field(count("id"), "total")])
+        .from("book")
+        .group_by([ops::rollup(("year", "lang"))]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT year, lang, count(id) AS total FROM book GROUP BY ROLLUP (year, lang)"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "SELECT `year`, `lang`, `count`(`id`) AS `total` FROM `book` GROUP BY `year`, `lang` WITH ROLLUP"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+        "SELECT \"year\", \"lang\", \"count\"(\"id\") AS \"total\" FROM \"book\" GROUP BY \"year\", \"lang\" \
+         UNION ALL SELECT \"year\", ? AS \"lang\", \"count\"(\"id\") AS \"total\" FROM \"book\" GROUP BY \"year\" \
+         UNION ALL SELECT ? AS \"year\", ? AS \"lang\", \"count\"(\"id\") AS \"total\" FROM \"book\""
+    );
+
+    let query = select(["year", "lang"])
+        .select([ops::as_field(ops::grouping(("year", "lang")), "level")])
+        .from("book")
+        .group_by([ops::cube(("year", "lang"))])
+        .order_by(["level"]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT year, lang, GROUPING(year, lang) AS level FROM book GROUP BY CUBE (year, lang) ORDER BY level"
+    );
+
+    #[cfg(feature = "sqlite")]
+    {
+        let (sql, args) = query.clone().to_sql::<sqlx::Sqlite>().unwrap();
+        assert_eq!(sql.matches("UNION ALL").count(), 3);
+        assert!(sql.ends_with(" ORDER BY \"level\""));
+        assert_eq!(
+            args.into_iter()
+                .filter(|arg| matches!(arg, crate::value::Value::Int(_)))
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 3].map(crate::value::Value::Int),
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    assert!(query
+        .to_sql::<sqlx::MySql>()
+        .unwrap()
+        .0
+        .contains("UNION ALL"));
+
+    // an unknown function may be an aggregate, it can not be emulated.
+    #[cfg(feature = "sqlite")]
+    {
+        let query = select(["year"])
+            .from("book")
+            .group_by([ops::rollup(("year", "lang"))]);
+        assert!(query
+            .clone()
+            .select([crate::func::func("json_group_array", ["lang"])])
+            .to_sql::<sqlx::Sqlite>()
+            .is_err());
+        assert!(query
+            .clone()
+            .select([
+                crate::func::sum("id").over(crate::item::Window::default().partition_by(["lang"]))
+            ])
+            .to_sql::<sqlx::Sqlite>()
+            .is_err());
+        assert!(query
+            .select([crate::func::func("json_group_array", ["title"])])
+            .to_sql::<sqlx::Sqlite>()
+            .is_ok());
+    }
+
+    let query = select(["year"]).from("book").group_by([ops::grouping_sets([
+        vec!["year".into()],
+        Vec::<crate::expr::Expr>::new(),
+    ])]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT year FROM book GROUP BY GROUPING SETS ((year), ())"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn window() {
+    #[cfg(any(feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::func::{func, sum};
+    use crate::item::{FrameBound, Window};
+    use crate::ops;
+    use crate::stmt::select;
+
+    let query = select(["title"])
+        .select([
+            ops::as_field(func("row_number", Vec::<Expr>::new()).over("w"), "pos"),
+            ops::as_field(
+                sum("sold").over(
+                    Window::from("w").groups(FrameBound::Preceding(1), FrameBound::Following(1)),
+                ),
+                "near",
+            ),
+        ])
+        .from("book")
+        .having(ops::gt("sold", 0))
+        .window(
+            "w",
+            Window::default()
+                .partition_by(["author"])
+                .order_by([ops::desc("year")]),
+        )
+        .order_by(["title"]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT title, row_number() OVER w AS pos, sum(sold) OVER (w GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS near \
+         FROM book HAVING sold > 0 WINDOW w AS (PARTITION BY author ORDER BY year DESC) ORDER BY title"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+        "SELECT \"title\", \"row_number\"() OVER \"w\" AS \"pos\", \"sum\"(\"sold\") OVER (\"w\" GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS \"near\" \
+         FROM \"book\" HAVING \"sold\" > ? WINDOW \"w\" AS (PARTITION BY \"author\" ORDER BY \"year\" DESC) ORDER BY \"title\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.to_sql::<sqlx::MySql>().is_err());
+}
+
+#[test]
+#[cfg(test)]
+fn table_function() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ddl::Type;
+    use crate::func::func;
+    use crate::ops::{as_table, eq, join, rows_from, with_ordinality};
+
+    let query = crate::stmt::select([("t", "n"), ("r", "a")]).from(join(
+        as_table(
+            with_ordinality([func("generate_series", [1, 3]), func("unnest", ["tags"])]),
+            "t",
+        )
+        .columns(["n", "tag", "idx"]),
+        as_table(func("json_to_recordset", ["data"]), "r")
+            .column_types([("a", Type::Int), ("b", Type::Text)]),
+        eq(("t", "n"), ("r", "a")),
+    ));
+    assert_eq!(
+        query.to_string(),
+        "SELECT t.n, r.a FROM ROWS FROM (generate_series(1, 3), unnest(tags)) WITH ORDINALITY AS t (n, tag, idx) \
+         JOIN json_to_recordset(data) AS r (a INTEGER, b TEXT) ON t.n = r.a"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "SELECT \"t\".\"n\", \"r\".\"a\" FROM ROWS FROM (\"generate_series\"($1, $2), \"unnest\"(\"tags\")) \
+         WITH ORDINALITY AS \"t\" (\"n\", \"tag\", \"idx\") \
+         JOIN \"json_to_recordset\"(\"data\") AS \"r\" (\"a\" INTEGER, \"b\" TEXT) ON \"t\".\"n\" = \"r\".\"a\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+
+    let query = crate::stmt::select(["a"]).from(
+        crate::item::Table::from(func("json_to_record", ["data"]))
+            .column_types([("a", Type::Int), ("b", Type::Text)]),
+    );
+    assert_eq!(
+        query.to_string(),
+        "SELECT a FROM json_to_record(data) AS (a INTEGER, b TEXT)"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.to_sql::<sqlx::Postgres>().unwrap().0,
+        "SELECT \"a\" FROM \"json_to_record\"(\"data\") AS (\"a\" INTEGER, \"b\" TEXT)"
+    );
+
+    let query = crate::stmt::select(["n"])
+        .from(crate::item::Table::from(func("generate_series", [1, 3])).columns(["n"]));
+    assert_eq!(
+        query.to_string(),
+        "/* invalid statement: column aliases without a table alias */"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert!(matches!(
+        query.clone().to_sql::<sqlx::Postgres>(),
+        Err(crate::error::Error::Invalid { .. })
+    ));
+
+    #[cfg(feature = "sqlite")]
+    assert!(matches!(
+        query.to_sql::<sqlx::Sqlite>(),
+        Err(crate::error::Error::Invalid { .. })
+    ));
+
+    let query = crate::stmt::select(["value"]).from(rows_from([func("json_each", ["data"])]));
+    assert_eq!(query.to_string(), "SELECT value FROM json_each(data)");
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.to_sql::<sqlx::Sqlite>().unwrap().0,
+        "SELECT \"value\" FROM \"json_each\"(\"data\")"
+    );
+}
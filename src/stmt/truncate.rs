@@ -0,0 +1,77 @@
+use crate::item::TableRef;
+
+/// `TRUNCATE` statement builder.
+///
+/// Sqlite has no `TRUNCATE`, it is rendered as `DELETE FROM table` which
+/// sqlite optimizes the same way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Truncate<'a> {
+    pub(crate) table: TableRef<'a>,
+    pub(crate) restart_identity: bool,
+    pub(crate) cascade: bool,
+}
+
+crate::macros::gen_display!(Truncate<'_>);
+
+impl<'a> std::convert::From<Truncate<'a>> for crate::stmt::Stmt<'a> {
+    #[inline]
+    fn from(val: Truncate<'a>) -> Self {
+        crate::stmt::Stmt::Truncate(val)
+    }
+}
+
+impl<'a> Truncate<'a> {
+    /// Reset identity columns. Mysql always does it, sqlite can not.
+    pub fn restart_identity(mut self) -> Truncate<'a> {
+        self.restart_identity = true;
+        self
+    }
+
+    /// Also truncate tables referencing this one, postgres only.
+    pub fn cascade(mut self) -> Truncate<'a> {
+        self.cascade = true;
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+
+    let query = crate::stmt::truncate("book");
+    assert_eq!(query.to_string(), "TRUNCATE book");
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query
+            .clone()
+            .restart_identity()
+            .to_sql::<sqlx::MySql>()
+            .unwrap()
+            .0,
+        "TRUNCATE TABLE `book`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Sqlite>().unwrap().0,
+        "DELETE FROM \"book\""
+    );
+
+    let query = query.restart_identity().cascade();
+    assert_eq!(query.to_string(), "TRUNCATE book RESTART IDENTITY CASCADE");
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "TRUNCATE \"book\" RESTART IDENTITY CASCADE"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert!(query.clone().to_sql::<sqlx::MySql>().is_err());
+
+    #[cfg(feature = "sqlite")]
+    assert!(query.to_sql::<sqlx::Sqlite>().is_err());
+}
@@ -11,6 +11,8 @@ pub struct Update<'a> {
     pub(crate) set: clause::Set<'a>,
     pub(crate) from: Option<clause::From<'a>>,
     pub(crate) filter: Option<clause::Where<'a>>,
+    pub(crate) orders: Option<clause::OrderBy<'a>>,
+    pub(crate) limit: Option<clause::Limit>,
     pub(crate) returns: Option<clause::Returning<'a>>,
 }
 
@@ -36,6 +38,11 @@ impl<'a> Update<'a> {
         self
     }
 
+    /// Add table(s) to `FROM` clause, joined to the updated table by
+    /// [`filter`](Update::filter).
+    ///
+    /// Mysql lacks `UPDATE ... FROM`, so it is rendered there as
+    /// `UPDATE t JOIN other ON filter SET ...`.
     pub fn from<T>(mut self, tables: T) -> Update<'a>
     where
         T: Into<clause::From<'a>>,
@@ -84,6 +91,31 @@ impl<'a> Update<'a> {
         self
     }
 
+    /// Add `ORDER BY` clause, only meaningful together with
+    /// [`limit`](Update::limit).
+    pub fn order_by<O>(mut self, orders: O) -> Update<'a>
+    where
+        O: Into<clause::OrderBy<'a>>,
+    {
+        self.orders = match self.orders.take() {
+            Some(mut inner) => {
+                inner.0.extend(orders.into().0);
+                Some(inner)
+            }
+            None => Some(orders.into()),
+        };
+        self
+    }
+
+    /// Limit number of affected rows.
+    ///
+    /// Native on mysql, emulated with a `rowid` subquery on sqlite and not
+    /// supported on postgres.
+    pub fn limit(mut self, limit: u32) -> Update<'a> {
+        self.limit = Some(clause::Limit(limit));
+        self
+    }
+
     /// Set/Add field(s) to `RETURNING` clause.
     ///
     /// Successive calls combine adds more field into the clause.
@@ -130,4 +162,35 @@ fn test() {
         .filter(crate::ops::eq(("user", "id"), ("data", "id")))
         .returning(["id", "age"]);
     assert_eq!(query.to_string(), "UPDATE user SET id = 1, age = 30, name = 'someone' FROM data WHERE user.id = data.id RETURNING id, age");
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        crate::build::ToSql::to_sql::<sqlx::MySql>(
+            crate::stmt::update("user")
+                .set("name", ("data", "name"))
+                .from(["data"])
+                .filter(crate::ops::eq(("user", "id"), ("data", "id")))
+        )
+        .unwrap()
+        .0,
+        "UPDATE `user` JOIN `data` ON `user`.`id` = `data`.`id` SET `user`.`name` = `data`.`name`"
+    );
+
+    let query = crate::stmt::update("user").set("age", crate::expr::Expr::Default);
+    assert_eq!(query.to_string(), "UPDATE user SET age = DEFAULT");
+
+    let query = crate::stmt::update("user")
+        .set("active", false)
+        .order_by([crate::ops::asc("seen")])
+        .limit(1);
+    assert_eq!(
+        query.to_string(),
+        "UPDATE user SET active = false ORDER BY seen ASC LIMIT 1"
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        crate::build::ToSql::to_sql::<sqlx::MySql>(query).unwrap().0,
+        "UPDATE `user` SET `active` = ? ORDER BY `seen` ASC LIMIT ?"
+    );
 }
@@ -29,3 +29,68 @@ impl<'a> Values<'a> {
         }
     }
 }
+
+#[test]
+#[cfg(test)]
+fn test() {
+    #[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+    use crate::build::ToSql;
+    use crate::ops::{as_table, eq, join};
+
+    let (a, b) = (&"a".to_string(), &"b".to_string());
+    let query = crate::stmt::select([("book", "title"), ("v", "name")]).from(join(
+        "book",
+        as_table(crate::stmt::values([(1, a), (2, b)]), "v").columns(["id", "name"]),
+        eq(("book", "id"), ("v", "id")),
+    ));
+    assert_eq!(
+        query.to_string(),
+        "SELECT book.title, v.name FROM book JOIN (VALUES (1, 'a'), (2, 'b')) AS v (id, name) ON book.id = v.id"
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::Postgres>().unwrap().0,
+        "SELECT \"book\".\"title\", \"v\".\"name\" FROM \"book\" \
+         JOIN (VALUES ($1, $2), ($3, $4)) AS \"v\" (\"id\", \"name\") ON \"book\".\"id\" = \"v\".\"id\""
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_eq!(
+        query.clone().to_sql::<sqlx::MySql>().unwrap().0,
+        "SELECT `book`.`title`, `v`.`name` FROM `book` \
+         JOIN (VALUES ROW(?, ?), ROW(?, ?)) AS `v` (`id`, `name`) ON `book`.`id` = `v`.`id`"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        query.to_sql::<sqlx::Sqlite>().unwrap().0,
+        "SELECT \"book\".\"title\", \"v\".\"name\" FROM \"book\" \
+         JOIN (SELECT \"column1\" AS \"id\", \"column2\" AS \"name\" FROM (VALUES (?, ?), (?, ?))) AS \"v\" \
+         ON \"book\".\"id\" = \"v\".\"id\""
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert_eq!(
+        crate::stmt::select(["n"])
+            .from(as_table(crate::stmt::select([1, 2]), "t").columns(["n", "m"]))
+            .to_sql::<sqlx::Sqlite>()
+            .unwrap()
+            .0,
+        "SELECT \"n\" FROM (SELECT ? AS \"n\", ? AS \"m\") AS \"t\""
+    );
+
+    #[cfg(feature = "sqlite")]
+    for table in [
+        as_table(crate::stmt::values([(1, a), (2, b)]), "v").columns(["id"]),
+        as_table(crate::stmt::select([1, 2]), "t").columns(["n"]),
+        as_table(crate::stmt::select(["*"]).from("book"), "t").columns(["n"]),
+    ] {
+        assert!(matches!(
+            crate::stmt::select(["n"])
+                .from(table)
+                .to_sql::<sqlx::Sqlite>(),
+            Err(crate::error::Error::Invalid { .. })
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::expr::Expr;
 use crate::item::FuncCall;
+use crate::item::Ident;
 use crate::item::TableRef;
 use crate::stmt::data::Data;
 use crate::stmt::select::Select;
@@ -18,8 +19,21 @@ pub enum TableExpr<'a> {
     NaturalRightJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
     NaturalFullJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
     CrossJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
+    JoinUsing(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Vec<Ident<'a>>),
+    LeftJoinUsing(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Vec<Ident<'a>>),
+    RightJoinUsing(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Vec<Ident<'a>>),
+    FullJoinUsing(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Vec<Ident<'a>>),
 
     SubQuery(Data<'a>),
+    /// `LATERAL (subquery) AS alias`, the subquery may refer to preceding
+    /// tables.
+    Lateral(Box<crate::stmt::result::Result<'a>>, Ident<'a>),
+    /// `ROWS FROM (..)` of set returning functions, followed by
+    /// `WITH ORDINALITY` when the flag is set. A single function is rendered
+    /// on its own.
+    RowsFrom(Vec<FuncCall<'a>>, bool),
+    /// Aliased table as an operand of a join.
+    Aliased(Box<crate::item::Table<'a>>),
 }
 
 crate::macros::gen_display!(TableExpr<'_>);
@@ -119,4 +133,33 @@ fn test() {
         query.to_string(),
         "SELECT sub.one, sub.two FROM (SELECT 1 AS one, 2 AS two) AS sub"
     );
+
+    let query = crate::ops::join_using("a", "b", ["id", "kind"]);
+    assert_eq!(query.to_string(), "a JOIN b USING (id, kind)");
+    let query = crate::ops::left_join_using("a", "b", ["id"]);
+    assert_eq!(query.to_string(), "a LEFT JOIN b USING (id)");
+    let query = crate::ops::right_join_using("a", "b", ["id"]);
+    assert_eq!(query.to_string(), "a RIGHT JOIN b USING (id)");
+    let query = crate::ops::full_join_using("a", "b", ["id"]);
+    assert_eq!(query.to_string(), "a FULL JOIN b USING (id)");
+
+    let query = left_join(
+        "author",
+        crate::ops::lateral(
+            select(["title"])
+                .from("book")
+                .filter(eq(("book", "author_id"), ("author", "id")))
+                .order_by([crate::ops::desc("year")])
+                .limit(1),
+            "latest",
+        ),
+        true,
+    );
+    assert_eq!(
+        query.to_string(),
+        "author LEFT JOIN LATERAL (SELECT title FROM book WHERE book.author_id = author.id ORDER BY year DESC LIMIT 1) AS latest ON true"
+    );
+
+    #[cfg(feature = "sqlite")]
+    assert!(crate::build::ToSql::to_sql::<sqlx::Sqlite>(query).is_err());
 }
@@ -1,5 +1,9 @@
 use std::marker::PhantomData;
 
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use xql_derive::Value;
+
 macro_rules! into_value {
     ($($from:ty => $into:ident,)+) => {
         $(
@@ -10,6 +14,13 @@ macro_rules! into_value {
                 }
             }
 
+            impl<'a> std::convert::From<&'a $from> for Value<'a> {
+                #[inline]
+                fn from(val: &'a $from) -> Self {
+                    Value::$into(*val)
+                }
+            }
+
             impl std::convert::From<Option<$from>> for Value<'_>
             {
                 #[inline]
@@ -66,17 +77,130 @@ pub enum Value<'a> {
     Text(&'a str),
     Bytes(&'a [u8]),
 
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    NaiveDate(chrono::NaiveDate),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    NaiveTime(chrono::NaiveTime),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    NaiveDateTime(chrono::NaiveDateTime),
+
     #[cfg(feature = "use-chrono")]
     #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
     DateTime(chrono::DateTime<chrono::Utc>),
 
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    FixedDateTime(chrono::DateTime<chrono::FixedOffset>),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    Date(time::Date),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    Time(time::Time),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    PrimitiveDateTime(time::PrimitiveDateTime),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    OffsetDateTime(time::OffsetDateTime),
+
     #[cfg(feature = "use-decimal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "use-decimal")))]
     Numeric(rust_decimal::Decimal),
+
+    /// A user defined value. See [`CustomValue`].
+    Custom(&'a dyn CustomValue),
 }
 
 crate::macros::gen_display!(Value<'_>);
 
+/// A user defined type that can be used as a [`Value`].
+///
+/// Each backend has its own encoder so the value can be bound with its real
+/// database type instead of being converted into one of the built in variants
+/// first. A backend without an encoder fails to bind the value.
+///
+/// # Examples
+///
+/// ```
+/// use xql::value::CustomValue;
+///
+/// #[derive(Debug)]
+/// struct Cents(i64);
+///
+/// impl CustomValue for Cents {
+///     fn write_literal(&self, buff: &mut String) {
+///         buff.push_str(&self.0.to_string());
+///     }
+///
+///     #[cfg(feature = "postgres")]
+///     fn postgres(&self) -> Option<&dyn xql::exec::bind::Encoder<sqlx::Postgres>> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// let price = Cents(1250);
+/// assert_eq!(xql::eq("price", &price).to_string(), "price = 1250");
+/// ```
+pub trait CustomValue: std::fmt::Debug + Sync {
+    /// Write the value as an SQL literal. This is used when a statement is
+    /// displayed instead of executed.
+    fn write_literal(&self, buff: &mut String);
+
+    /// Encoder used to bind the value on postgres.
+    #[cfg(feature = "postgres")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+    fn postgres(&self) -> Option<&dyn crate::exec::bind::Encoder<sqlx::Postgres>> {
+        None
+    }
+
+    /// Encoder used to bind the value on mysql.
+    #[cfg(feature = "mysql")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+    fn mysql(&self) -> Option<&dyn crate::exec::bind::Encoder<sqlx::MySql>> {
+        None
+    }
+
+    /// Encoder used to bind the value on sqlite.
+    #[cfg(feature = "sqlite")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+    fn sqlite(&self) -> Option<&dyn crate::exec::bind::Encoder<sqlx::Sqlite>> {
+        None
+    }
+}
+
+/// Custom values are equal when their literals are equal.
+impl PartialEq for dyn CustomValue + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = String::new();
+        let mut right = String::new();
+        self.write_literal(&mut left);
+        other.write_literal(&mut right);
+        left == right
+    }
+}
+
+impl Eq for dyn CustomValue + '_ {}
+
+impl<'a, T> std::convert::From<&'a T> for Value<'a>
+where
+    T: CustomValue,
+{
+    #[inline]
+    fn from(val: &'a T) -> Self {
+        Value::Custom(val)
+    }
+}
+
 /// 👻
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Null<'a> {
@@ -92,10 +216,42 @@ pub enum Null<'a> {
     Text(PhantomData<&'a str>),
     Bytes(PhantomData<&'a [u8]>),
 
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    NaiveDate(PhantomData<chrono::NaiveDate>),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    NaiveTime(PhantomData<chrono::NaiveTime>),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    NaiveDateTime(PhantomData<chrono::NaiveDateTime>),
+
     #[cfg(feature = "use-chrono")]
     #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
     DateTime(PhantomData<chrono::DateTime<chrono::Utc>>),
 
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    FixedDateTime(PhantomData<chrono::DateTime<chrono::FixedOffset>>),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    Date(PhantomData<time::Date>),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    Time(PhantomData<time::Time>),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    PrimitiveDateTime(PhantomData<time::PrimitiveDateTime>),
+
+    #[cfg(feature = "use-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-time")))]
+    OffsetDateTime(PhantomData<time::OffsetDateTime>),
+
     #[cfg(feature = "use-decimal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "use-decimal")))]
     Numeric(PhantomData<rust_decimal::Decimal>),
@@ -115,7 +271,19 @@ into_value!(
 
 #[cfg(feature = "use-chrono")]
 into_value!(
+    chrono::NaiveDate => NaiveDate,
+    chrono::NaiveTime => NaiveTime,
+    chrono::NaiveDateTime => NaiveDateTime,
     chrono::DateTime<chrono::Utc> => DateTime,
+    chrono::DateTime<chrono::FixedOffset> => FixedDateTime,
+);
+
+#[cfg(feature = "use-time")]
+into_value!(
+    time::Date => Date,
+    time::Time => Time,
+    time::PrimitiveDateTime => PrimitiveDateTime,
+    time::OffsetDateTime => OffsetDateTime,
 );
 
 #[cfg(feature = "use-decimal")]
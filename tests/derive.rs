@@ -96,3 +96,76 @@ fn delete() {
     let query = xql::delete(Book::table());
     assert_eq!(query.to_string(), expect);
 }
+
+#[derive(xql::Value)]
+struct BookId(i32);
+
+#[derive(xql::Value)]
+struct Title(String);
+
+#[derive(xql::Value)]
+struct Id<T>(T)
+where
+    T: Copy;
+
+#[derive(Clone, Copy, xql::Value)]
+#[xql(rename_all = "snake_case")]
+enum Genre {
+    ScienceFiction,
+    Fantasy,
+    #[xql(rename = "nonfiction")]
+    NonFiction,
+}
+
+#[derive(xql::Value)]
+#[xql(repr = "i16")]
+enum Rating {
+    Bad = 1,
+    Good = 5,
+}
+
+#[test]
+fn value() {
+    use xql::value::Value;
+
+    assert_eq!(Value::from(BookId(1)), Value::Int(1));
+    assert_eq!(Value::from(&BookId(1)), Value::Int(1));
+
+    let title = Title("Dune".to_string());
+    assert_eq!(Value::from(&title), Value::Text("Dune"));
+
+    assert_eq!(Value::from(Id(7_i64)), Value::BigInt(7));
+    assert_eq!(Value::from(&Id(7_i16)), Value::SmallInt(7));
+
+    assert_eq!(
+        Value::from(Genre::ScienceFiction),
+        Value::Text("science_fiction")
+    );
+    assert_eq!(Value::from(&Genre::Fantasy), Value::Text("fantasy"));
+    assert_eq!(Value::from(Genre::NonFiction), Value::Text("nonfiction"));
+
+    assert_eq!(Value::from(Rating::Bad), Value::SmallInt(1));
+    assert_eq!(Value::from(&Rating::Good), Value::SmallInt(5));
+
+    let query = xql::select([Book::title])
+        .from(Book)
+        .filter(xql::eq(Book::id, BookId(1)));
+    assert_eq!(
+        query.to_string(),
+        "SELECT book.title FROM book WHERE book.id = 1"
+    );
+
+    let query = xql::insert(Book, [Book::id, Book::title]).values([(BookId(1), &title)]);
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO book(id, title) VALUES (1, 'Dune')"
+    );
+
+    let query = xql::update(Book)
+        .set(Book::title, &title)
+        .filter(xql::eq(Book::id, BookId(1)));
+    assert_eq!(
+        query.to_string(),
+        "UPDATE book SET title = 'Dune' WHERE book.id = 1"
+    );
+}
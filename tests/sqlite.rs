@@ -21,3 +21,613 @@ async fn test() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(three, 3);
     Ok(())
 }
+
+#[derive(Debug)]
+struct Cents(i64);
+
+impl xql::value::CustomValue for Cents {
+    fn write_literal(&self, buff: &mut String) {
+        buff.push_str(&self.0.to_string());
+    }
+
+    fn sqlite(&self) -> Option<&dyn xql::exec::bind::Encoder<Sqlite>> {
+        Some(&self.0)
+    }
+}
+
+#[tokio::test]
+async fn custom_value() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+
+    let price = Cents(1250);
+    let query = select([xql::add(&price, 1).alias("price")]);
+
+    let result = query.fetch_one(&pool).await?;
+    let price: i64 = result.try_get("price")?;
+    assert_eq!(price, 1251);
+    Ok(())
+}
+
+#[tokio::test]
+async fn upsert() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE book (id INTEGER PRIMARY KEY, title TEXT NOT NULL)")
+        .execute(&pool)
+        .await?;
+
+    let dune = &"Dune".to_string();
+    let messiah = &"Dune Messiah".to_string();
+    xql::insert("book", ["id", "title"])
+        .values([(1, dune)])
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("book", ["id", "title"])
+        .values([(1, messiah)])
+        .ignore()
+        .fetch_all(&pool)
+        .await?;
+    let title: String = select(["title"])
+        .from("book")
+        .fetch_one_scalar(&pool)
+        .await?;
+    assert_eq!(title, "Dune");
+
+    xql::insert("book", ["id", "title"])
+        .values([(1, messiah)])
+        .on_conflict(["id"])
+        .do_update([("title", xql::excluded("title"))])
+        .fetch_all(&pool)
+        .await?;
+    let title: String = select(["title"])
+        .from("book")
+        .fetch_one_scalar(&pool)
+        .await?;
+    assert_eq!(title, "Dune Messiah");
+    Ok(())
+}
+
+#[tokio::test]
+async fn default_values() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE book (id INTEGER PRIMARY KEY, title TEXT DEFAULT 'untitled')")
+        .execute(&pool)
+        .await?;
+
+    xql::insert("book", ["id", "title"])
+        .default_values()
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("book", ["id", "title"])
+        .values([(xql::expr::Expr::Default, xql::expr::Expr::Default)])
+        .fetch_all(&pool)
+        .await?;
+    let titles: Vec<String> = select(["title"])
+        .from("book")
+        .fetch_all_scalar(&pool)
+        .await?;
+    assert_eq!(titles, ["untitled", "untitled"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn distinct_on() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE book (author TEXT, title TEXT, year INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query(
+        "INSERT INTO book VALUES ('Herbert', 'Dune', 1965), ('Herbert', 'Dune Messiah', 1969), ('Tolkien', 'The Hobbit', 1937)",
+    )
+    .execute(&pool)
+    .await?;
+
+    let latest: Vec<(String, String)> = select(["author", "title"])
+        .distinct_on(["author"])
+        .from("book")
+        .order_by(["author".into(), xql::desc("year")])
+        .fetch_all_as(&pool)
+        .await?;
+    assert_eq!(
+        latest,
+        [
+            ("Herbert".to_string(), "Dune Messiah".to_string()),
+            ("Tolkien".to_string(), "The Hobbit".to_string()),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE book (id INTEGER PRIMARY KEY, year INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO book VALUES (1, 1965), (2, 1937), (3, 1954)")
+        .execute(&pool)
+        .await?;
+
+    xql::delete("book")
+        .order_by(["year"])
+        .limit(2)
+        .fetch_all(&pool)
+        .await?;
+    let ids: Vec<i32> = select(["id"])
+        .from("book")
+        .offset(0)
+        .fetch_first(10)
+        .fetch_all_scalar(&pool)
+        .await?;
+    assert_eq!(ids, [1]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rollup() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE book (year INTEGER, lang TEXT)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO book VALUES (1965, 'en'), (1965, 'fr'), (1937, 'en')")
+        .execute(&pool)
+        .await?;
+
+    let totals: Vec<(Option<i32>, Option<String>, i32, i32)> = select(["year", "lang"])
+        .select([
+            xql::as_field(xql::count("lang"), "total"),
+            xql::as_field(xql::grouping(("year", "lang")), "level"),
+        ])
+        .from("book")
+        .group_by([xql::rollup(("year", "lang"))])
+        .order_by(["level".into(), xql::desc("year"), "lang".into()])
+        .fetch_all_as(&pool)
+        .await?;
+    assert_eq!(
+        totals,
+        [
+            (Some(1965), Some("en".to_string()), 1, 0),
+            (Some(1965), Some("fr".to_string()), 1, 0),
+            (Some(1937), Some("en".to_string()), 1, 0),
+            (Some(1965), None, 2, 1),
+            (Some(1937), None, 1, 1),
+            (None, None, 3, 3),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn window() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::item::{FrameBound, Window};
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE book (author TEXT, year INTEGER, sold INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query(
+        "INSERT INTO book VALUES ('Herbert', 1965, 10), ('Herbert', 1969, 5), ('Tolkien', 1937, 7)",
+    )
+    .execute(&pool)
+    .await?;
+
+    let rows: Vec<(i32, i32, i32)> = select(["year"])
+        .select([
+            xql::as_field(
+                xql::func("rank", Vec::<xql::expr::Expr>::new()).over("w"),
+                "pos",
+            ),
+            xql::as_field(
+                xql::sum("sold").over(
+                    Window::from("w").rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+                ),
+                "total",
+            ),
+        ])
+        .from("book")
+        .window(
+            "w",
+            Window::default()
+                .partition_by(["author"])
+                .order_by(["year"]),
+        )
+        .order_by(["year"])
+        .fetch_all_as(&pool)
+        .await?;
+    assert_eq!(rows, [(1937, 1, 7), (1965, 1, 10), (1969, 2, 15)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_using() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE author (id INTEGER PRIMARY KEY, banned BOOLEAN)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("CREATE TABLE book (id INTEGER PRIMARY KEY, author_id INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO author VALUES (1, false), (2, true)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO book VALUES (1, 1), (2, 2), (3, 2)")
+        .execute(&pool)
+        .await?;
+
+    xql::delete("book")
+        .using(["author"])
+        .filter(xql::eq(("book", "author_id"), ("author", "id")))
+        .filter(("author", "banned"))
+        .fetch_all(&pool)
+        .await?;
+    let ids: Vec<i32> = select(["id"]).from("book").fetch_all_scalar(&pool).await?;
+    assert_eq!(ids, [1]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn join_using() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE author (author_id INTEGER PRIMARY KEY, banned BOOLEAN)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("CREATE TABLE book (id INTEGER PRIMARY KEY, author_id INTEGER)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO author VALUES (1, false), (2, true), (3, true)")
+        .execute(&pool)
+        .await?;
+    sqlx::query("INSERT INTO book VALUES (1, 1), (2, 2), (3, 2), (4, 3)")
+        .execute(&pool)
+        .await?;
+
+    let ids: Vec<i32> = select([("book", "id")])
+        .from(xql::ops::join_using("book", "author", ["author_id"]))
+        .filter(("author", "banned"))
+        .order_by([("book", "id")])
+        .fetch_all_scalar(&pool)
+        .await?;
+    assert_eq!(ids, [2, 3, 4]);
+
+    // rendered as `WHERE EXISTS (..)`, only the matching rows go away.
+    let mut deleted: Vec<i32> = xql::delete("book")
+        .using([xql::as_table("author", "a")])
+        .filter(xql::eq(("book", "author_id"), ("a", "author_id")))
+        .filter(xql::eq(("a", "author_id"), 2))
+        .returning([("book", "id")])
+        .fetch_all_scalar(&pool)
+        .await?;
+    deleted.sort_unstable();
+    assert_eq!(deleted, [2, 3]);
+    let ids: Vec<i32> = select(["id"])
+        .from("book")
+        .order_by(["id"])
+        .fetch_all_scalar(&pool)
+        .await?;
+    assert_eq!(ids, [1, 4]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_table() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::ddl::{column, primary_key, RefAction, Type};
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    let untitled = &"untitled".to_string();
+    xql::create_table("author")
+        .column(column("id", Type::Int).primary_key().identity())
+        .column(column("name", Type::Text).not_null().unique())
+        .fetch_all(&pool)
+        .await?;
+    xql::create_table("book")
+        .if_not_exists()
+        .column(column("id", Type::Int).primary_key().identity())
+        .column(
+            column("author_id", Type::Int)
+                .not_null()
+                .references("author", "id")
+                .on_delete(RefAction::Cascade),
+        )
+        .column(column("title", Type::Text).not_null().default(untitled))
+        .column(column("price", Type::Numeric(10, 2)).check(xql::ge("price", 0)))
+        .column(column("double", Type::Numeric(10, 2)).generated(xql::mul("price", 2)))
+        .fetch_all(&pool)
+        .await?;
+    xql::create_table("book_tag")
+        .column(column("book_id", Type::Int).not_null())
+        .column(column("tag", Type::Varchar(32)).not_null())
+        .constraint(primary_key(["book_id", "tag"]))
+        .fetch_all(&pool)
+        .await?;
+
+    let herbert = &"Herbert".to_string();
+    xql::insert("author", ["name"])
+        .values([(herbert,)])
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("book", ["author_id", "price"])
+        .values([(1, 10)])
+        .fetch_all(&pool)
+        .await?;
+    let book: (i32, String, i32) = select(["id", "title", "double"])
+        .from("book")
+        .fetch_one_as(&pool)
+        .await?;
+    assert_eq!(book, (1, "untitled".to_string(), 20));
+
+    assert!(xql::insert("book", ["author_id", "price"])
+        .values([(1, -1)])
+        .fetch_all(&pool)
+        .await
+        .is_err());
+    assert!(xql::insert("author", ["name"])
+        .values([(herbert,)])
+        .fetch_all(&pool)
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn alter_table() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::ddl::{check, column, Type};
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    let schema = xql::create_table("book")
+        .column(column("id", Type::Int).primary_key())
+        .column(column("name", Type::Text).not_null())
+        .column(column("price", Type::Int));
+    schema.clone().fetch_all(&pool).await?;
+    let index = xql::create_index("book_name").on("book", ["name"]);
+    index.clone().fetch_all(&pool).await?;
+    xql::create_table("review")
+        .column(
+            column("book_id", Type::Int)
+                .references("book", "id")
+                .on_delete(xql::ddl::RefAction::Cascade),
+        )
+        .fetch_all(&pool)
+        .await?;
+    let dune = &"Dune".to_string();
+    xql::insert("book", ["id", "name", "price"])
+        .values([(1, dune, 10)])
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("review", ["book_id"])
+        .values([(1,)])
+        .fetch_all(&pool)
+        .await?;
+
+    xql::alter_table("book")
+        .add_column(column("year", Type::Int))
+        .drop_column("year")
+        .fetch_all(&pool)
+        .await?;
+
+    xql::alter_table("book")
+        .schema(schema)
+        .index(index)
+        .rename_column("name", "title")
+        .alter_type("price", Type::Numeric(10, 2))
+        .add_constraint(check(xql::ge("price", 0)).named("positive_price"))
+        .add_column(column("year", Type::Int))
+        .fetch_all(&pool)
+        .await?;
+
+    let indexes: Vec<String> = select(["name"])
+        .from("sqlite_master")
+        .filter(xql::eq("type", "index"))
+        .filter(xql::eq("tbl_name", "book"))
+        .fetch_all_scalar(&pool)
+        .await?;
+    assert_eq!(indexes, ["book_name"]);
+
+    let book: (i32, String, i32) = select(["id", "title", "price"])
+        .from("book")
+        .fetch_one_as(&pool)
+        .await?;
+    assert_eq!(book, (1, "Dune".to_string(), 10));
+    // the rebuild did not cascade to the referencing rows.
+    let reviews: i32 = select([xql::func("count", ["book_id"])])
+        .from("review")
+        .fetch_one_scalar(&pool)
+        .await?;
+    assert_eq!(reviews, 1);
+    assert!(xql::insert("book", ["id", "title", "price"])
+        .values([(2, dune, -1)])
+        .fetch_all(&pool)
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_index() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::ddl::{column, Type};
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    xql::create_table("book")
+        .column(column("title", Type::Text).not_null())
+        .column(column("deleted", Type::Bool).not_null())
+        .fetch_all(&pool)
+        .await?;
+    xql::create_index("book_title")
+        .unique()
+        .if_not_exists()
+        .on("book", [xql::func("lower", ["title"])])
+        .filter(xql::eq("deleted", false))
+        .fetch_all(&pool)
+        .await?;
+
+    let (dune, upper) = (&"Dune".to_string(), &"DUNE".to_string());
+    xql::insert("book", ["title", "deleted"])
+        .values([(dune, true), (upper, false)])
+        .fetch_all(&pool)
+        .await?;
+    assert!(xql::insert("book", ["title", "deleted"])
+        .values([(dune, false)])
+        .fetch_all(&pool)
+        .await
+        .is_err());
+
+    xql::drop_index("book_title")
+        .if_exists()
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("book", ["title", "deleted"])
+        .values([(dune, false)])
+        .fetch_all(&pool)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_view() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::ddl::{column, Type};
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    xql::create_table("book")
+        .column(column("id", Type::Int).primary_key())
+        .column(column("price", Type::Int).not_null())
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("book", ["id", "price"])
+        .values([(1, 5), (2, 20), (3, 8)])
+        .fetch_all(&pool)
+        .await?;
+    xql::create_view(
+        "cheap",
+        select(["id"]).from("book").filter(xql::lt("price", 10)),
+    )
+    .columns(["book_id"])
+    .fetch_all(&pool)
+    .await?;
+
+    let ids: Vec<(i32,)> = select(["book_id"])
+        .from("cheap")
+        .order_by(["book_id"])
+        .fetch_all_as(&pool)
+        .await?;
+    assert_eq!(ids, [(1,), (3,)]);
+
+    xql::drop_view("cheap").if_exists().fetch_all(&pool).await?;
+    assert!(select(["book_id"])
+        .from("cheap")
+        .fetch_all(&pool)
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_table() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::ddl::{column, Type};
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    xql::create_table("book")
+        .column(column("id", Type::Int).primary_key())
+        .fetch_all(&pool)
+        .await?;
+    xql::insert("book", ["id"])
+        .values([(1,), (2,)])
+        .fetch_all(&pool)
+        .await?;
+
+    xql::truncate("book").fetch_all(&pool).await?;
+    let count: i32 = select([xql::count("id")])
+        .from("book")
+        .fetch_one_scalar(&pool)
+        .await?;
+    assert_eq!(count, 0);
+
+    xql::drop_table("book").if_exists().fetch_all(&pool).await?;
+    xql::drop_table("book").if_exists().fetch_all(&pool).await?;
+    assert!(xql::drop_table("book").fetch_all(&pool).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn table_function() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::blanket::TableExprExt;
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    let dune = &"Dune".to_string();
+    let tags = &r#"["scifi","classic"]"#.to_string();
+    let rows: Vec<(String, String)> = select([("b", "title"), ("t", "value")])
+        .from(
+            xql::as_table(select([dune.alias("title"), tags.alias("tags")]), "b").join(
+                xql::as_table(xql::func("json_each", [("b", "tags")]), "t"),
+                true,
+            ),
+        )
+        .order_by([("t", "key")])
+        .fetch_all_as(&pool)
+        .await?;
+    assert_eq!(
+        rows,
+        [
+            ("Dune".to_string(), "scifi".to_string()),
+            ("Dune".to_string(), "classic".to_string()),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn values_alias() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    let (dune, emma) = (&"Dune".to_string(), &"Emma".to_string());
+    let rows: Vec<(i32, String)> = select([("v", "id"), ("v", "title")])
+        .from(
+            xql::as_table(xql::stmt::values([(2, emma), (1, dune)]), "v").columns(["id", "title"]),
+        )
+        .order_by([("v", "id")])
+        .fetch_all_as(&pool)
+        .await?;
+    assert_eq!(rows, [(1, "Dune".to_string()), (2, "Emma".to_string())]);
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(feature = "explain")]
+async fn explain() -> Result<(), Box<dyn std::error::Error>> {
+    use xql::ddl::{column, Type};
+    use xql::ops::eq;
+
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    xql::create_table("book")
+        .column(column("id", Type::Int).primary_key())
+        .column(column("title", Type::Text))
+        .column(column("year", Type::Int))
+        .fetch_all(&pool)
+        .await?;
+    xql::create_index("book_year")
+        .on("book", ["year"])
+        .fetch_all(&pool)
+        .await?;
+
+    let query = select(["title"]).from("book").filter(eq("year", 1965));
+    let plan = xql::exec::explain(xql::explain(query), &pool).await?;
+    let node = plan.nodes().find(|node| node.node == "SEARCH").unwrap();
+    assert_eq!(node.relation.as_deref(), Some("book"));
+    assert!(plan.uses_index("book_year"));
+
+    let query = select(["year"]).from("book").filter(eq("title", 1965));
+    let plan = xql::exec::explain(xql::explain(query), &pool).await?;
+    assert!(!plan.uses_index("book_year"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn cte_materialized() -> Result<(), Box<dyn std::error::Error>> {
+    let pool = Pool::<Sqlite>::connect("sqlite::memory:").await?;
+    let row: (i32, i32) = select([("a", "n"), ("b", "n")])
+        .from(["a", "b"])
+        .with("a", select([1.alias("n")]))
+        .materialized()
+        .with("b", select([2.alias("n")]))
+        .not_materialized()
+        .fetch_one_as(&pool)
+        .await?;
+    assert_eq!(row, (1, 2));
+    Ok(())
+}